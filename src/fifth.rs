@@ -19,6 +19,13 @@ struct Node<T> {
 
 type Link<T> = Option<NonNull<Node<T>>>;
 
+impl<T> Node<T> {
+    fn alloc(item: T) -> NonNull<Node<T>> {
+        let node = Box::new(Node { item, next: None });
+        unsafe { NonNull::new_unchecked(Box::into_raw(node)) }
+    }
+}
+
 impl<T> Default for List<T> {
     fn default() -> Self {
         Self { inner: None }
@@ -34,8 +41,7 @@ impl<T> List<T> {
     }
 
     pub fn push(&mut self, item: T) {
-        let new_tail = Box::new(Node { item, next: None });
-        let new_tail = unsafe { NonNull::new_unchecked(Box::into_raw(new_tail)) };
+        let new_tail = Node::alloc(item);
 
         let new_head = if let Some(Inner { head, tail }) = self.inner.take() {
             unsafe {
@@ -52,6 +58,42 @@ impl<T> List<T> {
         });
     }
 
+    /// Pushes every item in order. The new nodes are linked into a chain of their own first,
+    /// which is then attached to the tail in one step.
+    pub fn enqueue_bulk<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        let mut items = items.into_iter();
+        let Some(first) = items.next() else {
+            return;
+        };
+
+        // the chain is owned by a list of its own while it's being built, so that it's freed if
+        // the iterator panics; only its head needs to be right for that
+        let head = Node::alloc(first);
+        let mut chain = List {
+            inner: Some(Inner { head, tail: head }),
+        };
+
+        let mut tail = head;
+        for item in items {
+            let node = Node::alloc(item);
+            unsafe {
+                (*tail.as_ptr()).next = Some(node);
+            }
+            tail = node;
+        }
+        chain.inner = None;
+
+        match self.inner.as_mut() {
+            Some(inner) => {
+                unsafe {
+                    (*inner.tail.as_ptr()).next = Some(head);
+                }
+                inner.tail = tail;
+            }
+            None => self.inner = Some(Inner { head, tail }),
+        }
+    }
+
     pub fn pop(&mut self) -> Option<T> {
         self.inner.take().map(|Inner { head, tail }| {
             let Node { item, next } = unsafe { *Box::from_raw(head.as_ptr()) };
@@ -192,6 +234,41 @@ mod test {
         assert_eq!(list.pop(), None);
     }
 
+    #[test]
+    fn enqueue_bulk() {
+        let mut list = List::new();
+        list.enqueue_bulk(0..0);
+        assert_eq!(list.pop(), None);
+
+        list.enqueue_bulk([1, 2]);
+        list.push(3);
+        list.enqueue_bulk(vec![4, 5, 6]);
+        assert_eq!(list.pop(), Some(1));
+        list.enqueue_bulk(Some(7));
+        list.push(8);
+
+        assert!(list.into_iter().eq(2..9));
+    }
+
+    #[test]
+    fn enqueue_bulk_panic() {
+        let mut list = List::new();
+        list.push(String::from("kept"));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.enqueue_bulk((0..5).map(|i| {
+                if i == 3 {
+                    panic!("producer failed");
+                }
+                i.to_string()
+            }));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(list.pop().as_deref(), Some("kept"));
+        assert_eq!(list.pop(), None);
+    }
+
     #[test]
     fn into_iter() {
         let mut list = List::new();
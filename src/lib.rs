@@ -1,6 +1,4 @@
-#![feature(try_trait_v2)]
-#![feature(never_type)]
-#![feature(backtrace)]
+#![cfg_attr(feature = "nightly", feature(trusted_len))]
 
 pub mod fifth;
 pub mod sixth;
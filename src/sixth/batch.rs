@@ -0,0 +1,291 @@
+use std::mem;
+
+use super::{node::NodePtr, observer::Event, LinkedList};
+
+enum Edit<T> {
+    Insert(T),
+    Move(usize),
+}
+
+/// Edits to a [`LinkedList`] queued up by [`LinkedList::batch_edit`], to be applied together.
+///
+/// Every index refers to a position in the list as it was before any of the edits, so a diff
+/// computed against the list can be queued as is. Elements inserted or moved to the same
+/// position end up in the order they were queued.
+#[must_use = "queued edits do nothing until `apply` is called"]
+pub struct BatchEdit<'a, T> {
+    list: &'a mut LinkedList<T>,
+    // (position, edit) in the order they were queued
+    targets: Vec<(usize, Edit<T>)>,
+    // (index, whether the element is removed rather than moved), in the order they were queued
+    detached: Vec<(usize, bool)>,
+}
+
+impl<T> LinkedList<T> {
+    /// Starts queuing insertions, removals and moves, given by index, that [`BatchEdit::apply`]
+    /// then carries out in a single walk over the list, rather than one walk per edit.
+    ///
+    /// There are no variants taking a [`NodeHandle`]: [`LinkedList::remove_handle`] and
+    /// [`LinkedList::move_handle_to_front`] already reach the element in constant time, while
+    /// queuing a handle would mean walking the list to find its index, which is what batching
+    /// avoids.
+    ///
+    /// [`NodeHandle`]: super::NodeHandle
+    pub fn batch_edit(&mut self) -> BatchEdit<'_, T> {
+        BatchEdit {
+            list: self,
+            targets: Vec::new(),
+            detached: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> BatchEdit<'a, T> {
+    /// Queues `item` to be inserted before the element originally at `index`, or at the back if
+    /// `index == len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) -> &mut Self {
+        self.check_position(index);
+        self.targets.push((index, Edit::Insert(item)));
+        self
+    }
+
+    /// Queues the element originally at `index` to be removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> &mut Self {
+        self.check_element(index);
+        self.detached.push((index, true));
+        self
+    }
+
+    /// Queues the element originally at `from` to be moved in front of the element originally
+    /// at `to`, or to the back if `to == len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from >= len` or `to > len`.
+    pub fn move_to(&mut self, from: usize, to: usize) -> &mut Self {
+        self.check_element(from);
+        self.check_position(to);
+        self.detached.push((from, false));
+        self.targets.push((to, Edit::Move(from)));
+        self
+    }
+
+    fn check_position(&self, index: usize) {
+        assert!(
+            index <= self.list.len,
+            "insertion index {} out of bounds for a list of length {}",
+            index,
+            self.list.len
+        );
+    }
+
+    fn check_element(&self, index: usize) {
+        assert!(
+            index < self.list.len,
+            "index {} out of bounds for a list of length {}",
+            index,
+            self.list.len
+        );
+    }
+
+    /// Applies every queued edit in one walk over the list and returns the removed elements, in
+    /// their original order. The queue is left empty, with indices now referring to the list as
+    /// edited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element was queued to be removed or moved more than once.
+    pub fn apply(&mut self) -> LinkedList<T> {
+        let list = &mut *self.list;
+        let mut targets = mem::take(&mut self.targets);
+        let mut detached = mem::take(&mut self.detached);
+        let len = list.len;
+
+        targets.sort_by_key(|&(position, _)| position);
+        detached.sort_by_key(|&(index, _)| index);
+        let target_positions: Vec<usize> = targets.iter().map(|&(position, _)| position).collect();
+        if let Some(pair) = detached.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            panic!("element {} is removed or moved more than once", pair[0].0);
+        }
+
+        // the one walk: every position an edit touches, paired with its node
+        let mut positions: Vec<usize> = target_positions
+            .iter()
+            .copied()
+            .chain(detached.iter().map(|&(index, _)| index))
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+
+        let dummy = list.init();
+        let mut nodes = Vec::with_capacity(positions.len());
+        let mut node = dummy.next();
+        let mut index = 0;
+        for position in positions {
+            while index < position {
+                node = node.next();
+                index += 1;
+            }
+            nodes.push((position, node));
+        }
+        let node_at = |index: usize| {
+            let at = nodes.binary_search_by_key(&index, |&(index, _)| index);
+            nodes[at.unwrap()].1
+        };
+        let is_detached = |index: usize| {
+            detached
+                .binary_search_by_key(&index, |&(index, _)| index)
+                .is_ok()
+        };
+
+        unsafe {
+            for &(index, _) in &detached {
+                let node = node_at(index);
+                NodePtr::unlink(node, node, 1, list);
+            }
+
+            // A detached node still points at its original successor, so the first remaining
+            // node at or after a position can be found by following those pointers. That has to
+            // happen before anything is spliced in and the detached nodes are relinked.
+            let anchors: Vec<NodePtr<T>> = targets
+                .iter()
+                .map(|&(position, _)| {
+                    let (mut index, mut node) = (position, node_at(position));
+                    while index < len && is_detached(index) {
+                        node = node.next();
+                        index += 1;
+                    }
+                    node
+                })
+                .collect();
+
+            for ((_, edit), anchor) in targets.into_iter().zip(anchors) {
+                match edit {
+                    Edit::Insert(item) => anchor.insert_before(item, list),
+                    Edit::Move(from) => {
+                        let node = node_at(from);
+                        anchor.splice_before(node, node, 1, list);
+                    }
+                }
+            }
+        }
+
+        let mut removed = list.new_sibling();
+        let removed_dummy = removed.init();
+        for &(index, is_removed) in &detached {
+            if is_removed {
+                let node = node_at(index);
+                unsafe { removed_dummy.splice_before(node, node, 1, &mut removed) };
+            }
+        }
+
+        // observers see every removal, moves included, from the back, and then every insertion
+        // at its final position from the front
+        for &(index, _) in detached.iter().rev() {
+            list.notify(Event::Remove(index, 1));
+        }
+        for (inserted, &position) in target_positions.iter().enumerate() {
+            let detached_before = detached.partition_point(|&(index, _)| index < position);
+            list.notify(Event::Insert(position - detached_before + inserted));
+        }
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
+
+    #[test]
+    fn test_batch_edit() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        let recorder = Recorder::default();
+        list.set_observer(recorder.clone());
+
+        let removed = list
+            .batch_edit()
+            .remove(1)
+            .insert(3, 30)
+            .move_to(5, 0)
+            .insert(6, 60)
+            .move_to(2, 3)
+            .apply();
+
+        assert!(list.iter().eq(&[5, 0, 30, 2, 3, 4, 60]));
+        assert!(list.iter().rev().eq(&[60, 4, 3, 2, 30, 0, 5]));
+        assert_eq!(list.len(), 7);
+        assert!(removed.iter().eq(&[1]));
+
+        // replaying the events on the original gives the same list
+        let mut replayed: Vec<i32> = (0..6).collect();
+        let mut inserted = [5, 30, 2, 60].into_iter();
+        for event in recorder.take() {
+            match event {
+                Event::Remove(index, 1) => {
+                    replayed.remove(index);
+                }
+                Event::Insert(index) => replayed.insert(index, inserted.next().unwrap()),
+                event => panic!("unexpected event {:?}", event),
+            }
+        }
+        assert!(list.iter().eq(&replayed));
+    }
+
+    #[test]
+    fn test_batch_edit_adjacent() {
+        let mut list: LinkedList<char> = "abcde".chars().collect();
+
+        let mut edit = list.batch_edit();
+        edit.remove(1)
+            .remove(2)
+            .insert(2, 'x')
+            .insert(1, 'y')
+            .move_to(0, 3);
+        let removed = edit.apply();
+        assert!(list.iter().eq(&['y', 'x', 'a', 'd', 'e']));
+        assert!(removed.iter().eq(&['b', 'c']));
+
+        let mut edit = list.batch_edit();
+        edit.move_to(4, 4).move_to(0, 5).insert(5, 'z');
+        edit.apply();
+        assert!(list.iter().eq(&['x', 'a', 'd', 'e', 'y', 'z']));
+    }
+
+    #[test]
+    fn test_batch_edit_empty() {
+        let mut list = LinkedList::new();
+        list.batch_edit().insert(0, 1).insert(0, 2).apply();
+        assert!(list.iter().eq(&[1, 2]));
+
+        assert!(list.batch_edit().apply().is_empty());
+        let removed = list.batch_edit().remove(0).remove(1).apply();
+        assert!(list.is_empty());
+        assert!(removed.iter().eq(&[1, 2]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_edit_twice() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.batch_edit().remove(1).move_to(1, 0).apply();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_edit_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        let _ = list.batch_edit().remove(3);
+    }
+}
@@ -0,0 +1,207 @@
+use std::{borrow::Borrow, cmp::Ordering};
+
+use super::LinkedList;
+
+impl<T> LinkedList<T> {
+    pub fn starts_with(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len >= other.len && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+
+    pub fn ends_with(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len >= other.len
+            && self
+                .iter()
+                .rev()
+                .zip(other.iter().rev())
+                .all(|(a, b)| a == b)
+    }
+
+    /// Lexicographically compares the list with any sequence of `T` or references to `T`.
+    pub fn cmp_with<I>(&self, other: I) -> Ordering
+    where
+        T: Ord,
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        let mut other = other.into_iter();
+        for item in self {
+            let Some(other) = other.next() else {
+                return Ordering::Greater;
+            };
+
+            match item.cmp(other.borrow()) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+
+        if other.next().is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Returns whether `other` is a rotation of the list, i.e. whether the two are equal when
+    /// both are read as rings. Runs in O(n) by searching for `self` in `other` read around twice
+    /// with Knuth-Morris-Pratt.
+    pub fn eq_rotation(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.len != other.len {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+
+        let pattern: Vec<&T> = self.iter().collect();
+
+        // fallback[i] is the length of the longest proper prefix of pattern[..=i] that is also
+        // a suffix of it
+        let mut fallback = vec![0; pattern.len()];
+        let mut matched = 0;
+        for i in 1..pattern.len() {
+            while matched > 0 && pattern[i] != pattern[matched] {
+                matched = fallback[matched - 1];
+            }
+            if pattern[i] == pattern[matched] {
+                matched += 1;
+            }
+            fallback[i] = matched;
+        }
+
+        let mut matched = 0;
+        for item in other.iter().chain(other.iter().take(other.len - 1)) {
+            while matched > 0 && item != pattern[matched] {
+                matched = fallback[matched - 1];
+            }
+            if item == pattern[matched] {
+                matched += 1;
+                if matched == pattern.len() {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+// Comparisons with contiguous sequences, so that a list can be checked against a literal
+// without collecting it first. Like the impls for `Vec`, the element types may differ.
+impl<T: PartialEq<U>, U> PartialEq<[U]> for LinkedList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for LinkedList<T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for LinkedList<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<&[U; N]> for LinkedList<T> {
+    fn eq(&self, other: &&[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for LinkedList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use crate::sixth::LinkedList;
+
+    fn list_from(v: &[i32]) -> LinkedList<i32> {
+        v.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_eq_slices() {
+        let list = list_from(&[1, 2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3][..]);
+        assert_eq!(list, &[1, 2, 3][..]);
+
+        assert_ne!(list, [1, 2]);
+        assert_ne!(list, [1, 2, 4]);
+        assert_ne!(list, vec![1, 2, 3, 4]);
+        assert_eq!(LinkedList::<i32>::new(), []);
+
+        let strings: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(strings, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_starts_ends_with() {
+        let list = list_from(&[1, 2, 3, 4]);
+
+        assert!(list.starts_with(&list_from(&[])));
+        assert!(list.starts_with(&list_from(&[1, 2])));
+        assert!(list.starts_with(&list));
+        assert!(!list.starts_with(&list_from(&[2])));
+        assert!(!list.starts_with(&list_from(&[1, 2, 3, 4, 5])));
+
+        assert!(list.ends_with(&list_from(&[])));
+        assert!(list.ends_with(&list_from(&[3, 4])));
+        assert!(list.ends_with(&list));
+        assert!(!list.ends_with(&list_from(&[3])));
+        assert!(!list.ends_with(&list_from(&[0, 1, 2, 3, 4])));
+
+        assert!(list_from(&[]).starts_with(&list_from(&[])));
+        assert!(list_from(&[]).ends_with(&list_from(&[])));
+    }
+
+    #[test]
+    fn test_cmp_with() {
+        let list = list_from(&[1, 2, 3]);
+
+        assert_eq!(list.cmp_with([1, 2, 3]), Ordering::Equal);
+        assert_eq!(list.cmp_with([1, 2, 3].iter()), Ordering::Equal);
+        assert_eq!(list.cmp_with(vec![1, 2]), Ordering::Greater);
+        assert_eq!(list.cmp_with(1..5), Ordering::Less);
+        assert_eq!(list.cmp_with([1, 3]), Ordering::Less);
+        assert_eq!(list.cmp_with(&list), Ordering::Equal);
+        assert_eq!(list_from(&[]).cmp_with(0..0), Ordering::Equal);
+        assert_eq!(list_from(&[]).cmp_with([0]), Ordering::Less);
+    }
+
+    #[test]
+    fn test_eq_rotation() {
+        let list = list_from(&[1, 2, 1, 2, 3]);
+
+        assert!(list.eq_rotation(&list));
+        assert!(list.eq_rotation(&list_from(&[2, 3, 1, 2, 1])));
+        assert!(list.eq_rotation(&list_from(&[3, 1, 2, 1, 2])));
+        assert!(!list.eq_rotation(&list_from(&[2, 1, 1, 2, 3])));
+        assert!(!list.eq_rotation(&list_from(&[1, 2, 1, 2])));
+        assert!(!list.eq_rotation(&list_from(&[3, 2, 1, 2, 1])));
+
+        assert!(list_from(&[7]).eq_rotation(&list_from(&[7])));
+        assert!(!list_from(&[7]).eq_rotation(&list_from(&[8])));
+        assert!(list_from(&[0, 0, 0]).eq_rotation(&list_from(&[0, 0, 0])));
+        assert!(list_from(&[]).eq_rotation(&list_from(&[])));
+    }
+}
@@ -0,0 +1,689 @@
+//! A drop-in replacement for [`std::collections::LinkedList`].
+//!
+//! [`LinkedList`] here has the same methods, with the same names, signatures and panics, as the
+//! one in `std`, including the unstable cursor API, so switching over is a matter of changing an
+//! import. The full [`sixth::LinkedList`](super::LinkedList) is one conversion away through
+//! [`LinkedList::as_list`], [`LinkedList::list_mut`] or [`LinkedList::into_inner`].
+
+use std::{fmt::Debug, hash::Hash};
+
+use super::{observer::Event, DrainFilter};
+
+pub use super::{IntoIter, Iter, IterMut};
+
+#[repr(transparent)]
+pub struct LinkedList<T>(super::LinkedList<T>);
+
+pub struct Cursor<'a, T> {
+    inner: super::Cursor<'a, T>,
+}
+
+pub struct CursorMut<'a, T> {
+    inner: super::CursorMut<'a, T>,
+}
+
+impl<T> LinkedList<T> {
+    pub const fn new() -> Self {
+        Self(super::LinkedList::new())
+    }
+
+    // Safety: LinkedList is a transparent wrapper
+    fn from_list_ref(list: &super::LinkedList<T>) -> &Self {
+        unsafe { &*(list as *const super::LinkedList<T> as *const Self) }
+    }
+
+    pub fn as_list(&self) -> &super::LinkedList<T> {
+        &self.0
+    }
+
+    pub fn list_mut(&mut self) -> &mut super::LinkedList<T> {
+        &mut self.0
+    }
+
+    pub fn into_inner(self) -> super::LinkedList<T> {
+        self.0
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.0.append(&mut other.0);
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            inner: self.0.cursor_front(),
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            inner: self.0.cursor_front_mut(),
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            inner: self.0.cursor_back(),
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            inner: self.0.cursor_back_mut(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.contains(x)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.0.front()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.0.front_mut()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.0.back()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.0.back_mut()
+    }
+
+    pub fn push_front(&mut self, elt: T) {
+        self.0.push_front(elt)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    pub fn push_back(&mut self, elt: T) {
+        self.0.push_back(elt)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "Cannot split off at a nonexistent index");
+
+        Self(self.0.split_off(at))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `at >= len`.
+    pub fn remove(&mut self, at: usize) -> T {
+        assert!(
+            at < self.len(),
+            "Cannot remove at an index outside of the list bounds"
+        );
+
+        self.0.remove(at).unwrap()
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item))
+    }
+
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        self.0.drain_filter(|item| !f(item)).for_each(drop)
+    }
+
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> DrainFilter<'_, T, F> {
+        self.0.drain_filter(filter)
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.inner.index()
+    }
+
+    pub fn move_next(&mut self) {
+        self.inner.move_next()
+    }
+
+    pub fn move_prev(&mut self) {
+        self.inner.move_prev()
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
+        self.inner.current()
+    }
+
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.inner.peek_next()
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        self.inner.peek_prev()
+    }
+
+    pub fn front(&self) -> Option<&'a T> {
+        self.inner.list.front()
+    }
+
+    pub fn back(&self) -> Option<&'a T> {
+        self.inner.list.back()
+    }
+
+    pub fn as_list(&self) -> &'a LinkedList<T> {
+        LinkedList::from_list_ref(self.inner.list)
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.inner.index()
+    }
+
+    pub fn move_next(&mut self) {
+        self.inner.move_next()
+    }
+
+    pub fn move_prev(&mut self) {
+        self.inner.move_prev()
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.inner.current()
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.inner.peek_next()
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        self.inner.peek_prev()
+    }
+
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            inner: self.inner.as_cursor(),
+        }
+    }
+
+    pub fn as_list(&self) -> &LinkedList<T> {
+        LinkedList::from_list_ref(self.inner.list())
+    }
+
+    pub fn insert_after(&mut self, item: T) {
+        self.inner.insert_after(item)
+    }
+
+    pub fn insert_before(&mut self, item: T) {
+        self.inner.insert_before(item)
+    }
+
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.inner.remove_current()
+    }
+
+    pub fn remove_current_as_list(&mut self) -> Option<LinkedList<T>> {
+        self.inner.remove_current_as_list().map(LinkedList)
+    }
+
+    pub fn splice_after(&mut self, list: LinkedList<T>) {
+        self.inner.splice_after(list.0)
+    }
+
+    pub fn splice_before(&mut self, list: LinkedList<T>) {
+        self.inner.splice_before(list.0)
+    }
+
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        LinkedList(self.inner.split_after())
+    }
+
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        LinkedList(self.inner.split_before())
+    }
+
+    pub fn push_front(&mut self, elt: T) {
+        self.inner.push_front(elt)
+    }
+
+    pub fn push_back(&mut self, elt: T) {
+        self.inner.push_back(elt)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let item = unsafe { self.inner.inner.pop_front(self.inner.list) }?;
+        self.inner.list.notify(Event::Remove(0, 1));
+        Some(item)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let item = unsafe { self.inner.inner.pop_back(self.inner.list) }?;
+        let index = self.inner.list.len;
+        self.inner.list.notify(Event::Remove(index, 1));
+        Some(item)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.inner.list.front()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.inner.list.front_mut()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.inner.list.back()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.inner.list.back_mut()
+    }
+}
+
+impl<'a, T> Clone for Cursor<'a, T> {
+    fn clone(&self) -> Self {
+        Cursor { inner: self.inner }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T> From<super::LinkedList<T>> for LinkedList<T> {
+    fn from(list: super::LinkedList<T>) -> Self {
+        Self(list)
+    }
+}
+
+impl<T> From<LinkedList<T>> for super::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(arr: [T; N]) -> Self {
+        Self(arr.into())
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0)
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Debug> Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<'a, T: Debug> Debug for Cursor<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Cursor")
+            .field(self.as_list())
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<'a, T: Debug> Debug for CursorMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorMut")
+            .field(self.as_list())
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IntoIter, Iter, IterMut, LinkedList};
+
+    #[test]
+    fn test_compat_basic() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut other: LinkedList<i32> = (4..6).collect();
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert!(list.iter().eq(&[1, 2, 3, 4, 5]));
+        assert!(list.contains(&4) && !list.contains(&6));
+
+        let mut tail = list.split_off(2);
+        assert!(list.iter().eq(&[1, 2]));
+        assert!(tail.iter().eq(&[3, 4, 5]));
+        assert!(list.split_off(2).is_empty());
+        assert_eq!(tail.split_off(0), LinkedList::from([3, 4, 5]));
+        assert!(tail.is_empty());
+
+        let mut list: LinkedList<i32> = (0..8).collect();
+        assert_eq!(list.remove(5), 5);
+        list.retain(|x| x % 3 != 0);
+        assert!(list.iter().eq(&[1, 2, 4, 7]));
+        let odd: Vec<_> = list.extract_if(|x| *x % 2 == 1).collect();
+        assert_eq!(odd, [1, 7]);
+        list.extend(&[8, 9]);
+        assert_eq!(format!("{:?}", list), "[2, 4, 8, 9]");
+
+        let list: crate::sixth::LinkedList<i32> = list.into();
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn test_compat_iter_traits() {
+        fn assert_fused<I: std::iter::FusedIterator>(_: &I) {}
+
+        let mut list = LinkedList::from([1, 2, 3]);
+        let mut iter = list.iter();
+        iter.next();
+        let copy = iter.clone();
+        assert!(iter.eq(copy.clone()));
+        assert_eq!(format!("{:?}", copy), "Iter([2, 3])");
+        assert_fused(&copy);
+
+        let mut iter = list.iter_mut();
+        iter.next_back();
+        assert_eq!(format!("{:?}", iter), "IterMut([1, 2])");
+        assert_fused(&iter);
+
+        let into_iter = list.clone().into_iter();
+        assert!(into_iter.clone().eq(list.clone()));
+        assert_eq!(format!("{:?}", into_iter), "IntoIter([1, 2, 3])");
+        assert_fused(&into_iter);
+
+        assert_eq!(Iter::<i32>::default().len(), 0);
+        assert_eq!(IterMut::<i32>::default().next(), None);
+        assert_eq!(IntoIter::<i32>::default().next(), None);
+    }
+
+    #[test]
+    fn test_compat_const_new() {
+        static SHARED: LinkedList<i32> = LinkedList::new();
+
+        let mut list = const { LinkedList::<String>::new() };
+        list.push_back("a".to_string());
+        assert_eq!(list.len(), 1);
+        assert!(SHARED.is_empty());
+        assert_eq!(SHARED.iter().next(), None);
+    }
+
+    #[test]
+    #[should_panic = "Cannot split off at a nonexistent index"]
+    fn test_compat_split_off_out_of_bounds() {
+        LinkedList::from([1, 2]).split_off(3);
+    }
+
+    // mirrors std's own cursor tests
+    #[test]
+    fn test_cursor_move_peek() {
+        let m: LinkedList<u32> = (1..7).collect();
+        let mut cursor = m.cursor_front();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.peek_next(), Some(&2));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+        assert_eq!(cursor.peek_prev(), Some(&6));
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(1));
+
+        let mut cursor = m.cursor_back();
+        assert_eq!(cursor.current(), Some(&6));
+        assert_eq!(cursor.index(), Some(5));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_prev(), Some(&6));
+        assert_eq!((cursor.front(), cursor.back()), (Some(&1), Some(&6)));
+        assert_eq!(cursor.as_list().len(), 6);
+
+        let empty = LinkedList::<u32>::new();
+        let cursor = empty.cursor_front();
+        assert_eq!((cursor.current(), cursor.index()), (None, None));
+    }
+
+    #[test]
+    fn test_cursor_mut_insert() {
+        let mut m: LinkedList<u32> = (1..7).collect();
+        let mut cursor = m.cursor_front_mut();
+        cursor.insert_before(7);
+        cursor.insert_after(8);
+        assert!(m.iter().eq(&[7, 1, 8, 2, 3, 4, 5, 6]));
+
+        let mut cursor = m.cursor_front_mut();
+        cursor.move_prev();
+        cursor.insert_before(9);
+        cursor.insert_after(10);
+        assert!(m.iter().eq(&[10, 7, 1, 8, 2, 3, 4, 5, 6, 9]));
+
+        let mut cursor = m.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(7));
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(9));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(10));
+        assert!(m.iter().eq(&[1, 8, 2, 3, 4, 5, 6]));
+
+        let mut cursor = m.cursor_front_mut();
+        let mut p: LinkedList<u32> = LinkedList::new();
+        p.extend([100, 101, 102, 103]);
+        let mut q: LinkedList<u32> = LinkedList::new();
+        q.extend([200, 201, 202, 203]);
+        cursor.splice_after(p);
+        cursor.splice_before(q);
+        assert!(m
+            .iter()
+            .eq(&[200, 201, 202, 203, 1, 100, 101, 102, 103, 8, 2, 3, 4, 5, 6]));
+
+        let mut cursor = m.cursor_front_mut();
+        cursor.move_prev();
+        let tmp = cursor.split_before();
+        assert!(m.is_empty());
+        let mut m = tmp;
+        let mut cursor = m.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        let tmp = cursor.split_after();
+        assert_eq!(cursor.index(), Some(6));
+        assert!(tmp.iter().eq(&[102, 103, 8, 2, 3, 4, 5, 6]));
+        assert!(m.iter().eq(&[200, 201, 202, 203, 1, 100, 101]));
+
+        let mut cursor = m.cursor_back_mut();
+        let front = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+        assert!(front.iter().eq(&[200, 201, 202, 203, 1, 100]));
+        assert_eq!(
+            cursor.remove_current_as_list(),
+            Some(LinkedList::from([101]))
+        );
+        assert!(cursor.current().is_none());
+        m.as_list().validate();
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_mut_push_pop() {
+        let mut m: LinkedList<u32> = LinkedList::new();
+        m.extend([1, 2, 3, 4, 5, 6]);
+        let mut c = m.cursor_front_mut();
+        assert_eq!(c.current(), Some(&mut 1));
+        assert_eq!(c.peek_next(), Some(&mut 2));
+        assert_eq!(c.peek_prev(), None);
+        assert_eq!(c.index(), Some(0));
+        c.move_prev();
+        assert_eq!(c.current(), None);
+        assert_eq!(c.peek_next(), Some(&mut 1));
+        assert_eq!(c.peek_prev(), Some(&mut 6));
+        assert_eq!(c.index(), None);
+        c.move_next();
+        c.move_next();
+        assert_eq!(c.current(), Some(&mut 2));
+        assert_eq!(c.peek_next(), Some(&mut 3));
+        assert_eq!(c.peek_prev(), Some(&mut 1));
+        assert_eq!(c.index(), Some(1));
+        let mut c2 = c.as_cursor();
+        assert_eq!(c2.current(), Some(&2));
+        assert_eq!(c2.index(), Some(1));
+        c2.move_next();
+        assert_eq!(c2.current(), Some(&3));
+
+        c.push_front(0);
+        c.push_back(7);
+        assert_eq!(c.index(), Some(2));
+        assert_eq!(c.current(), Some(&mut 2));
+        assert_eq!(c.pop_front(), Some(0));
+        assert_eq!(c.pop_back(), Some(7));
+        assert_eq!(c.index(), Some(1));
+
+        c.move_prev();
+        c.move_prev();
+        assert_eq!(c.index(), None);
+        c.push_back(7);
+        c.push_front(0);
+        assert_eq!(c.peek_prev(), Some(&mut 7));
+        assert_eq!(c.pop_back(), Some(7));
+        assert_eq!(c.peek_prev(), Some(&mut 6));
+
+        c.move_next();
+        assert_eq!(c.pop_front(), Some(0));
+        assert_eq!(c.index(), Some(0));
+        assert_eq!(c.current(), Some(&mut 1));
+        *c.front_mut().unwrap() += 10;
+        *c.back_mut().unwrap() += 10;
+        assert_eq!((c.front(), c.back()), (Some(&11), Some(&16)));
+
+        let mut c = m.cursor_back_mut();
+        assert_eq!(c.pop_back(), Some(16));
+        assert_eq!(c.index(), None);
+        assert_eq!(c.current(), None);
+        c.move_prev();
+        assert_eq!(c.index(), Some(4));
+        assert_eq!(c.current(), Some(&mut 5));
+        m.as_list().validate();
+        assert!(m.iter().eq(&[11, 2, 3, 4, 5]));
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::{self, VecDeque},
+    mem,
+};
+
+use super::{
+    node::{Node, NodePtr},
+    LinkedList,
+};
+
+// frees a detached node's allocation, without dropping its item, if the mapping closure panics
+struct DeallocOnUnwind<T>(NodePtr<T>);
+
+impl<T> Drop for DeallocOnUnwind<T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.0.dealloc_raw();
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Converts every element with `f`, preserving order.
+    ///
+    /// When `Node<T>` and `Node<U>` have the same layout, each mapped element is written back
+    /// into the allocation of the node it came from, so no node is freed or allocated apart from
+    /// the new list's dummy node. Otherwise the list is rebuilt node by node.
+    pub fn map<U, F: FnMut(T) -> U>(mut self, mut f: F) -> LinkedList<U> {
+        if !Node::<T>::same_layout::<U>() {
+            return self.into_iter().map(f).collect();
+        }
+
+        let mut res = self.new_sibling_of::<U>();
+        let dummy = res.init();
+
+        while let Some(node) = self.front_node() {
+            unsafe {
+                NodePtr::unlink(node, node, 1, &mut self);
+                let item = node.get_raw_unchecked().as_ptr().read();
+
+                let guard = DeallocOnUnwind(node);
+                let mapped = f(item);
+                mem::forget(guard);
+
+                let node = node.cast::<U>();
+                node.get_raw_unchecked().as_ptr().write(mapped);
+                dummy.splice_before(node, node, 1, &mut res);
+            }
+        }
+
+        res
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter().cloned());
+        vec
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    // collecting would round the allocation up, since the list's iterator isn't TrustedLen on
+    // stable
+    fn from(list: LinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len);
+        vec.extend(list);
+        vec
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for LinkedList<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> From<collections::LinkedList<T>> for LinkedList<T> {
+    fn from(list: collections::LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, collections::VecDeque, panic, rc::Rc};
+
+    use crate::sixth::LinkedList;
+
+    #[derive(Debug, PartialEq)]
+    struct Meters(u64);
+
+    #[test]
+    fn test_map_same_layout() {
+        let list: LinkedList<u64> = (0..5).collect();
+        let front = list.iter().next().map(|x| x as *const u64);
+
+        let meters = list.map(Meters);
+        assert!(meters
+            .iter()
+            .eq(&[Meters(0), Meters(1), Meters(2), Meters(3), Meters(4)]));
+        assert_eq!(meters.iter().next().map(|x| &x.0 as *const u64), front);
+        assert_eq!(meters.len(), 5);
+
+        let back: LinkedList<i64> = meters.map(|m| -(m.0 as i64));
+        assert!(back.iter().rev().eq(&[-4, -3, -2, -1, 0]));
+    }
+
+    #[test]
+    fn test_map_different_layout() {
+        let list: LinkedList<u8> = (0..4).collect();
+        let strings = list.map(|x| x.to_string().repeat(2));
+        assert!(strings.iter().eq(&["00", "11", "22", "33"]));
+
+        let lengths = strings.map(|s| s.len() as u8);
+        assert!(lengths.iter().eq(&[2, 2, 2, 2]));
+
+        assert!(LinkedList::<u32>::new().map(|x| x as i32).is_empty());
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(LinkedList::from([1, 2, 3]), [1, 2, 3]);
+        assert_eq!(LinkedList::from(vec![String::from("a")]), ["a"]);
+        assert!(LinkedList::<i32>::from([]).is_empty());
+
+        let mut deque: VecDeque<i32> = (3..6).collect();
+        deque.push_front(2);
+        let list: LinkedList<i32> = deque.into();
+        assert_eq!(list, [2, 3, 4, 5]);
+        assert!(list.iter().rev().eq(&[5, 4, 3, 2]));
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let list: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(list.to_vec(), ["a", "b", "c"]);
+        assert_eq!(list.to_vec().capacity(), 3);
+        assert_eq!(list.len(), 3);
+
+        let vec: Vec<String> = list.into();
+        assert_eq!(vec, ["a", "b", "c"]);
+        assert_eq!(vec.capacity(), 3);
+        assert!(Vec::from(LinkedList::<i32>::new()).is_empty());
+        assert_eq!(Vec::from(LinkedList::from(vec![1, 2])), [1, 2]);
+    }
+
+    #[test]
+    fn test_std_list() {
+        let mut std_list: std::collections::LinkedList<i32> = (0..4).collect();
+        std_list.push_front(-1);
+
+        let list = LinkedList::from(std_list);
+        assert_eq!(list, [-1, 0, 1, 2, 3]);
+        assert!(list.iter().rev().eq(&[3, 2, 1, 0, -1]));
+
+        let back: std::collections::LinkedList<i32> = list.into();
+        assert!(back.iter().eq(&[-1, 0, 1, 2, 3]));
+        assert!(LinkedList::from(std::collections::LinkedList::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_map_panic() {
+        let drops = Rc::new(Cell::new(0));
+
+        struct Counted(Rc<Cell<usize>>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let list: LinkedList<Counted> = (0..6).map(|_| Counted(drops.clone())).collect();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut count = 0;
+            list.map(|item| {
+                count += 1;
+                if count == 3 {
+                    panic!("mapping failed");
+                }
+                item
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 6);
+    }
+}
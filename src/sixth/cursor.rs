@@ -1,6 +1,6 @@
-use std::ops::Not;
+use std::{iter::Rev, mem, ops::Not};
 
-use super::{node::NodePtr, LinkedList};
+use super::{iter::RawIter, node::NodePtr, observer::Event, Iter, IterMut, LinkedList};
 
 pub(crate) struct RawCursor<T> {
     pub(crate) node: Option<NodePtr<T>>,
@@ -9,23 +9,42 @@ pub(crate) struct RawCursor<T> {
 
 impl<T> Clone for RawCursor<T> {
     fn clone(&self) -> Self {
-        Self {
-            node: self.node,
-            index: self.index,
-        }
+        *self
     }
 }
 
 impl<T> Copy for RawCursor<T> {}
 
 impl<T> RawCursor<T> {
-    fn new(list: &LinkedList<T>) -> Self {
+    pub(crate) fn new(list: &LinkedList<T>) -> Self {
         Self {
             node: list.dummy,
             index: list.len,
         }
     }
 
+    pub(crate) fn front(list: &LinkedList<T>) -> Self {
+        Self {
+            node: list.dummy.map(|dummy| dummy.next()),
+            index: 0,
+        }
+    }
+
+    pub(crate) fn back(list: &LinkedList<T>) -> Self {
+        Self {
+            node: list.dummy.map(|dummy| dummy.prev()),
+            index: list.len.saturating_sub(1),
+        }
+    }
+
+    // need to guarantee that node is at position index in the list
+    pub(crate) fn from_node(node: NodePtr<T>, index: usize) -> Self {
+        Self {
+            node: Some(node),
+            index,
+        }
+    }
+
     fn set_index(&mut self, index: usize, list: &LinkedList<T>) {
         self.index = index;
         if self.index > list.len {
@@ -34,11 +53,12 @@ impl<T> RawCursor<T> {
     }
 
     fn index_add(&mut self, inc: usize, list: &LinkedList<T>) {
-        self.set_index(self.index.wrapping_add(inc), list);
+        self.set_index(self.index + inc % (list.len + 1), list);
     }
 
     fn index_sub(&mut self, dec: usize, list: &LinkedList<T>) {
-        self.set_index(self.index.wrapping_sub(dec), list);
+        let modulus = list.len + 1;
+        self.set_index(self.index + modulus - dec % modulus, list);
     }
 
     fn index(&self, list: &LinkedList<T>) -> Option<usize> {
@@ -59,6 +79,67 @@ impl<T> RawCursor<T> {
         }
     }
 
+    // the elements after the current one, or the whole list if the cursor is on the ghost
+    fn raw_iter_after(&self, list: &LinkedList<T>) -> Option<RawIter<T>> {
+        let node = self.node?;
+        let len = match self.index(list) {
+            Some(index) => list.len - index - 1,
+            None => list.len,
+        };
+        Some(RawIter::new(node.next(), list.dummy?.prev(), len))
+    }
+
+    // the current element and the ones after it, which is none if the cursor is on the ghost
+    fn raw_iter_from(&self, list: &LinkedList<T>) -> Option<RawIter<T>> {
+        let node = self.node?;
+        let len = list.len - self.index(list).unwrap_or(list.len);
+        Some(RawIter::new(node, list.dummy?.prev(), len))
+    }
+
+    // the elements before the current one, or the whole list if the cursor is on the ghost
+    fn raw_iter_before(&self, list: &LinkedList<T>) -> Option<RawIter<T>> {
+        let node = self.node?;
+        let len = self.index(list).unwrap_or(list.len);
+        Some(RawIter::new(list.dummy?.next(), node.prev(), len))
+    }
+
+    // `index` must be at most len
+    fn seek(&mut self, index: usize, list: &LinkedList<T>) {
+        if let Some(node) = self.node {
+            self.node = list.walk_to(index, Some((node, self.index)));
+            self.index = index;
+        }
+    }
+
+    // moves up to `n` steps, stopping on the ghost, and returns how many steps were taken
+    fn move_next_n(&mut self, n: usize, list: &LinkedList<T>) -> usize {
+        let Some(node) = self.node else {
+            return 0;
+        };
+        let steps = n.min(match self.index(list) {
+            Some(index) => list.len - index,
+            None => list.len + 1,
+        });
+
+        self.node = Some((0..steps).fold(node, |node, _| node.next()));
+        self.index_add(steps, list);
+        steps
+    }
+
+    fn move_prev_n(&mut self, n: usize, list: &LinkedList<T>) -> usize {
+        let Some(node) = self.node else {
+            return 0;
+        };
+        let steps = n.min(match self.index(list) {
+            Some(index) => index + 1,
+            None => list.len + 1,
+        });
+
+        self.node = Some((0..steps).fold(node, |node, _| node.prev()));
+        self.index_sub(steps, list);
+        steps
+    }
+
     unsafe fn current<'a>(&self, list: &'a LinkedList<T>) -> Option<&'a T> {
         self.node?.get(list)
     }
@@ -83,6 +164,23 @@ impl<T> RawCursor<T> {
         self.node?.prev().get_mut(list)
     }
 
+    // the element `n + 1` steps forward, unless that reaches the ghost
+    fn nth_next(&self, n: usize, list: &LinkedList<T>) -> Option<NodePtr<T>> {
+        let node = self.node?;
+        let remaining = match self.index(list) {
+            Some(index) => list.len - index - 1,
+            None => list.len,
+        };
+        (n < remaining).then(|| (0..=n).fold(node, |node, _| node.next()))
+    }
+
+    // the element `n + 1` steps backward, unless that reaches the ghost
+    fn nth_prev(&self, n: usize, list: &LinkedList<T>) -> Option<NodePtr<T>> {
+        let node = self.node?;
+        let remaining = self.index(list).unwrap_or(list.len);
+        (n < remaining).then(|| (0..=n).fold(node, |node, _| node.prev()))
+    }
+
     fn init(&mut self, list: &mut LinkedList<T>) -> NodePtr<T> {
         *self.node.get_or_insert_with(|| list.init())
     }
@@ -99,10 +197,7 @@ impl<T> RawCursor<T> {
     unsafe fn insert_before(&mut self, item: T, list: &mut LinkedList<T>) {
         let node = self.init(list);
         node.insert_before(item, list);
-
-        if !node.is_dummy(list) {
-            self.index_add(1, list);
-        }
+        self.index_add(1, list);
     }
 
     unsafe fn remove_current(&mut self, list: &mut LinkedList<T>) -> Option<T> {
@@ -114,7 +209,10 @@ impl<T> RawCursor<T> {
         Some(item)
     }
 
-    unsafe fn remove_current_as_list(&mut self, list: &mut LinkedList<T>) -> Option<LinkedList<T>> {
+    pub(crate) unsafe fn remove_current_as_list(
+        &mut self,
+        list: &mut LinkedList<T>,
+    ) -> Option<LinkedList<T>> {
         let node = self.node.as_mut()?;
         let next = node.next();
 
@@ -124,23 +222,186 @@ impl<T> RawCursor<T> {
             list
         })
     }
+
+    // moves every element of `other` in after the current one, or to the front of the list if
+    // the cursor is on the ghost
+    pub(crate) unsafe fn splice_after(
+        &mut self,
+        other: &mut LinkedList<T>,
+        list: &mut LinkedList<T>,
+    ) {
+        let node = self.init(list);
+        let len = other.len;
+        list.transfer_all(node.next(), other);
+
+        if node.is_dummy(list) {
+            self.index += len;
+        }
+    }
+
+    // moves every element of `other` in before the current one, or to the back of the list if
+    // the cursor is on the ghost
+    pub(crate) unsafe fn splice_before(
+        &mut self,
+        other: &mut LinkedList<T>,
+        list: &mut LinkedList<T>,
+    ) {
+        let node = self.init(list);
+        self.index += other.len;
+        list.transfer_all(node, other);
+    }
+
+    // detaches everything after the current element, or the whole list if the cursor is on the
+    // ghost
+    pub(crate) unsafe fn split_after(&mut self, list: &mut LinkedList<T>) -> LinkedList<T> {
+        let Some(node) = self.node else {
+            return list.new_sibling();
+        };
+
+        let len = if node.is_dummy(list) {
+            self.index = 0;
+            list.len
+        } else {
+            list.len - self.index - 1
+        };
+
+        if len == 0 {
+            return list.new_sibling();
+        }
+        let back = list.dummy.unwrap_unchecked().prev();
+        NodePtr::slice_off_as_list(node.next(), back, len, list)
+    }
+
+    // detaches everything before the current element, or the whole list if the cursor is on
+    // the ghost
+    pub(crate) unsafe fn split_before(&mut self, list: &mut LinkedList<T>) -> LinkedList<T> {
+        let Some(node) = self.node else {
+            return list.new_sibling();
+        };
+
+        let len = if node.is_dummy(list) {
+            list.len
+        } else {
+            self.index
+        };
+        self.index = 0;
+
+        if len == 0 {
+            return list.new_sibling();
+        }
+        let front = list.dummy.unwrap_unchecked().next();
+        NodePtr::slice_off_as_list(front, node.prev(), len, list)
+    }
+
+    // the cursor keeps pointing at the same element, or at the ghost
+    pub(crate) unsafe fn push_front(&mut self, item: T, list: &mut LinkedList<T>) {
+        self.init(list);
+        list.init().insert_after(item, list);
+        self.index += 1;
+    }
+
+    // the cursor keeps pointing at the same element, or at the ghost
+    pub(crate) unsafe fn push_back(&mut self, item: T, list: &mut LinkedList<T>) {
+        let node = self.init(list);
+        list.init().insert_before(item, list);
+
+        if node.is_dummy(list) {
+            self.index += 1;
+        }
+    }
+
+    // a cursor on the popped element moves on to the next one, like `remove_current`
+    #[cfg_attr(not(feature = "std-compat"), allow(dead_code))]
+    pub(crate) unsafe fn pop_front(&mut self, list: &mut LinkedList<T>) -> Option<T> {
+        let front = list.front_node()?;
+        if self.node == Some(front) {
+            self.node = Some(front.next());
+        } else {
+            self.index -= 1;
+        }
+        Some(front.pop_unchecked(list))
+    }
+
+    // a cursor on the popped element moves on to the ghost
+    #[cfg_attr(not(feature = "std-compat"), allow(dead_code))]
+    pub(crate) unsafe fn pop_back(&mut self, list: &mut LinkedList<T>) -> Option<T> {
+        let back = list.back_node()?;
+        if self.node == Some(back) {
+            self.node = Some(back.next());
+        } else if self.index == list.len {
+            self.index -= 1;
+        }
+        Some(back.pop_unchecked(list))
+    }
 }
 
 pub struct Cursor<'a, T> {
-    inner: RawCursor<T>,
-    list: &'a LinkedList<T>,
+    pub(crate) inner: RawCursor<T>,
+    pub(crate) list: &'a LinkedList<T>,
 }
 
 pub struct CursorMut<'a, T> {
-    inner: RawCursor<T>,
-    list: &'a mut LinkedList<T>,
+    pub(crate) inner: RawCursor<T>,
+    pub(crate) list: &'a mut LinkedList<T>,
 }
 
+/// A saved cursor position, from [`Cursor::position`] or [`CursorMut::position`], that a cursor
+/// can be rebuilt at with [`LinkedList::cursor_from_position`] once the original is gone. Only
+/// the index is kept, so inserting or removing elements before it shifts what it refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CursorPosition(Option<usize>);
+
+impl<T> LinkedList<T> {
+    // the position's index, or len for the ghost, if it's still within the list
+    fn position_index(&self, position: CursorPosition) -> Option<usize> {
+        match position.0 {
+            Some(index) => (index < self.len).then_some(index),
+            None => Some(self.len),
+        }
+    }
+
+    /// Returns a cursor at `position`, or `None` if the list has become too short for it.
+    pub fn cursor_from_position(&self, position: CursorPosition) -> Option<Cursor<'_, T>> {
+        let index = self.position_index(position)?;
+        Some(Cursor::from_raw(self.raw_cursor_at(index), self))
+    }
+
+    pub fn cursor_mut_from_position(
+        &mut self,
+        position: CursorPosition,
+    ) -> Option<CursorMut<'_, T>> {
+        let index = self.position_index(position)?;
+        Some(CursorMut::from_raw(self.raw_cursor_at(index), self))
+    }
+}
+
+impl<'a, T> Clone for Cursor<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Cursor<'a, T> {}
+
+unsafe impl<'a, T: Sync> Send for Cursor<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Cursor<'a, T> {}
+
+unsafe impl<'a, T: Send> Send for CursorMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for CursorMut<'a, T> {}
+
 impl<'a, T> Cursor<'a, T> {
+    pub(crate) fn from_raw(inner: RawCursor<T>, list: &'a LinkedList<T>) -> Self {
+        Self { inner, list }
+    }
+
     pub fn index(&self) -> Option<usize> {
         self.inner.index(self.list)
     }
 
+    pub fn position(&self) -> CursorPosition {
+        CursorPosition(self.index())
+    }
+
     pub fn move_next(&mut self) {
         self.inner.move_next(self.list)
     }
@@ -149,27 +410,107 @@ impl<'a, T> Cursor<'a, T> {
         self.inner.move_prev(self.list)
     }
 
-    pub fn current(&self) -> Option<&T> {
+    /// Moves to `index`, taking the fewest steps from the current position, which may mean
+    /// going around through the ghost element. Position `len` is the ghost element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn seek(&mut self, index: usize) {
+        self.list.check_cursor_index(index);
+        self.inner.seek(index, self.list)
+    }
+
+    /// Moves up to `n` elements forward, stopping early on the ghost element, and returns how
+    /// many steps were taken. A cursor that starts on the ghost moves off it first.
+    pub fn move_next_n(&mut self, n: usize) -> usize {
+        self.inner.move_next_n(n, self.list)
+    }
+
+    /// Moves up to `n` elements backward, stopping early on the ghost element, and returns how
+    /// many steps were taken.
+    pub fn move_prev_n(&mut self, n: usize) -> usize {
+        self.inner.move_prev_n(n, self.list)
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
         // Safety:`self.inner` is a node of self.list
         unsafe { self.inner.current(self.list) }
     }
 
-    pub fn peek_next(&self) -> Option<&T> {
+    pub fn peek_next(&self) -> Option<&'a T> {
         // Safety:`self.inner` is a node of self.list
         unsafe { self.inner.peek_next(self.list) }
     }
 
-    pub fn peek_prev(&self) -> Option<&T> {
+    pub fn peek_prev(&self) -> Option<&'a T> {
         // Safety:`self.inner` is a node of self.list
         unsafe { self.inner.peek_prev(self.list) }
     }
+
+    /// Returns the element `n` places after the next one, so `peek_nth(0)` is `peek_next()`,
+    /// without moving the cursor. Looking past the back of the list gives `None` rather than
+    /// wrapping around through the ghost element.
+    pub fn peek_nth(&self, n: usize) -> Option<&'a T> {
+        // Safety: the node is an element of self.list
+        self.inner
+            .nth_next(n, self.list)
+            .map(|node| unsafe { node.get_unchecked() })
+    }
+
+    /// Returns the element `n` places before the previous one, so `peek_nth_back(0)` is
+    /// `peek_prev()`, without moving the cursor.
+    pub fn peek_nth_back(&self, n: usize) -> Option<&'a T> {
+        // Safety: the node is an element of self.list
+        self.inner
+            .nth_prev(n, self.list)
+            .map(|node| unsafe { node.get_unchecked() })
+    }
+
+    /// Moves forward from the current element until one matches `pred`, and returns it with the
+    /// cursor left on it. If none does, the cursor stops on the ghost element.
+    pub fn find<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&'a T> {
+        self.find_map(|item| pred(item).then_some(item))
+    }
+
+    /// Turns the cursor into an iterator over the elements after the current one, front to
+    /// back. On the ghost element, that's the whole list.
+    pub fn into_iter_after(self) -> Iter<'a, T> {
+        Iter::from_raw(self.inner.raw_iter_after(self.list))
+    }
+
+    /// Turns the cursor into an iterator over the elements before the current one, back to
+    /// front. On the ghost element, that's the whole list.
+    pub fn into_iter_before(self) -> Rev<Iter<'a, T>> {
+        Iter::from_raw(self.inner.raw_iter_before(self.list)).rev()
+    }
+
+    /// Moves forward from the current element until `f` returns `Some`, like
+    /// [`Cursor::find`].
+    pub fn find_map<B, F: FnMut(&'a T) -> Option<B>>(&mut self, mut f: F) -> Option<B> {
+        while let Some(item) = self.current() {
+            if let Some(res) = f(item) {
+                return Some(res);
+            }
+            self.move_next();
+        }
+        None
+    }
 }
 
 impl<'a, T> CursorMut<'a, T> {
+    pub(crate) fn from_raw(inner: RawCursor<T>, list: &'a mut LinkedList<T>) -> Self {
+        Self { inner, list }
+    }
+
     pub fn index(&self) -> Option<usize> {
         self.inner.index(self.list)
     }
 
+    pub fn position(&self) -> CursorPosition {
+        CursorPosition(self.index())
+    }
+
     pub fn move_next(&mut self) {
         self.inner.move_next(self.list)
     }
@@ -178,6 +519,29 @@ impl<'a, T> CursorMut<'a, T> {
         self.inner.move_prev(self.list)
     }
 
+    /// Moves to `index`, taking the fewest steps from the current position, which may mean
+    /// going around through the ghost element. Position `len` is the ghost element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn seek(&mut self, index: usize) {
+        self.list.check_cursor_index(index);
+        self.inner.seek(index, self.list)
+    }
+
+    /// Moves up to `n` elements forward, stopping early on the ghost element, and returns how
+    /// many steps were taken. A cursor that starts on the ghost moves off it first.
+    pub fn move_next_n(&mut self, n: usize) -> usize {
+        self.inner.move_next_n(n, self.list)
+    }
+
+    /// Moves up to `n` elements backward, stopping early on the ghost element, and returns how
+    /// many steps were taken.
+    pub fn move_prev_n(&mut self, n: usize) -> usize {
+        self.inner.move_prev_n(n, self.list)
+    }
+
     pub fn current(&mut self) -> Option<&mut T> {
         // Safety:`self.inner` is a node of self.list
         unsafe { self.inner.current_mut(self.list) }
@@ -193,6 +557,88 @@ impl<'a, T> CursorMut<'a, T> {
         unsafe { self.inner.peek_prev_mut(self.list) }
     }
 
+    /// Returns the element `n` places after the next one, like [`Cursor::peek_nth`].
+    pub fn peek_nth(&mut self, n: usize) -> Option<&mut T> {
+        // Safety: the node is an element of self.list
+        self.inner
+            .nth_next(n, self.list)
+            .map(|node| unsafe { node.get_mut_unchecked() })
+    }
+
+    /// Returns the element `n` places before the previous one, like [`Cursor::peek_nth_back`].
+    pub fn peek_nth_back(&mut self, n: usize) -> Option<&mut T> {
+        // Safety: the node is an element of self.list
+        self.inner
+            .nth_prev(n, self.list)
+            .map(|node| unsafe { node.get_mut_unchecked() })
+    }
+
+    /// Moves forward from the current element until one matches `pred`, and returns it with the
+    /// cursor left on it. If none does, the cursor stops on the ghost element.
+    pub fn find<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&mut T> {
+        loop {
+            match self.current() {
+                Some(item) if pred(item) => break,
+                Some(_) => self.move_next(),
+                None => return None,
+            }
+        }
+        self.current()
+    }
+
+    pub fn find_map<B, F: FnMut(&mut T) -> Option<B>>(&mut self, mut f: F) -> Option<B> {
+        while let Some(item) = self.current() {
+            if let Some(res) = f(item) {
+                return Some(res);
+            }
+            self.move_next();
+        }
+        None
+    }
+
+    /// Turns the cursor into an iterator over the elements after the current one, like
+    /// [`Cursor::into_iter_after`].
+    pub fn into_iter_after(self) -> IterMut<'a, T> {
+        IterMut::from_raw(self.inner.raw_iter_after(self.list))
+    }
+
+    /// Turns the cursor into an iterator over the elements before the current one, back to
+    /// front, like [`Cursor::into_iter_before`].
+    pub fn into_iter_before(self) -> Rev<IterMut<'a, T>> {
+        IterMut::from_raw(self.inner.raw_iter_before(self.list)).rev()
+    }
+
+    /// Splits the list at the cursor into two mutable iterators, one over the elements before
+    /// the current one and one over the current element and those after it, like
+    /// `slice::split_at_mut`. On the ghost element, the first half is the whole list.
+    pub fn split_iter_mut(&mut self) -> (IterMut<'_, T>, IterMut<'_, T>) {
+        (
+            IterMut::from_raw(self.inner.raw_iter_before(self.list)),
+            IterMut::from_raw(self.inner.raw_iter_from(self.list)),
+        )
+    }
+
+    /// Returns the list the cursor is in, to read its length or ends without giving up the
+    /// cursor.
+    pub fn list(&self) -> &LinkedList<T> {
+        self.list
+    }
+
+    /// Pushes `item` onto the front of the list. The cursor stays on the same element, or on
+    /// the ghost.
+    pub fn push_front(&mut self, item: T) {
+        unsafe { self.inner.push_front(item, self.list) };
+        self.list.notify(Event::Insert(0));
+    }
+
+    /// Pushes `item` onto the back of the list. The cursor stays on the same element, or on the
+    /// ghost.
+    pub fn push_back(&mut self, item: T) {
+        unsafe { self.inner.push_back(item, self.list) };
+        let index = self.list.len - 1;
+        self.list.notify(Event::Insert(index));
+    }
+
     pub fn as_cursor(&self) -> Cursor<'_, T> {
         Cursor {
             inner: self.inner,
@@ -201,24 +647,847 @@ impl<'a, T> CursorMut<'a, T> {
     }
 
     pub fn insert_after(&mut self, item: T) {
+        let index = self.index().map_or(0, |index| index + 1);
         unsafe {
             self.inner.insert_after(item, self.list);
         }
+        self.list.notify(Event::Insert(index));
     }
 
     pub fn insert_before(&mut self, item: T) {
+        let index = self.index().unwrap_or(self.list.len);
         unsafe {
             self.inner.insert_before(item, self.list);
         }
+        self.list.notify(Event::Insert(index));
     }
 
     pub fn remove_current(&mut self) -> Option<T> {
-        unsafe { self.inner.remove_current(self.list) }
+        let index = self.index()?;
+        let item = unsafe { self.inner.remove_current(self.list) }?;
+        self.list.notify(Event::Remove(index, 1));
+        Some(item)
+    }
+
+    /// Removes the current element as a single-element list, keeping its node, which can then be
+    /// spliced into another list without reallocating. The cursor moves on to the next element.
+    pub fn remove_current_as_list(&mut self) -> Option<LinkedList<T>> {
+        let index = self.index()?;
+        let list = unsafe { self.inner.remove_current_as_list(self.list) }?;
+        self.list.notify(Event::Remove(index, 1));
+        Some(list)
+    }
+
+    /// Moves every element of `list` in after the current one in constant time, or to the front
+    /// of the list if the cursor is on the ghost element.
+    pub fn splice_after(&mut self, mut list: LinkedList<T>) {
+        let index = self.index().map_or(0, |index| index + 1);
+        let len = list.len;
+        unsafe { self.inner.splice_after(&mut list, self.list) };
+        self.list.notify(Event::Splice(index, len));
+    }
+
+    /// Moves every element of `list` in before the current one in constant time, or to the back
+    /// of the list if the cursor is on the ghost element.
+    pub fn splice_before(&mut self, mut list: LinkedList<T>) {
+        let index = self.index().unwrap_or(self.list.len);
+        let len = list.len;
+        unsafe { self.inner.splice_before(&mut list, self.list) };
+        self.list.notify(Event::Splice(index, len));
     }
 
-    // pub fn remove_current_as_list(&mut self) -> Option<LinkedList<T>> {
-    //     unsafe {
-    //         self.inner.
-    //     }
-    // }
+    /// Detaches everything after the current element as a new list, or the whole list if the
+    /// cursor is on the ghost element.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let index = self.index().map_or(0, |index| index + 1);
+        let list = unsafe { self.inner.split_after(self.list) };
+        self.list.notify(Event::Remove(index, list.len));
+        list
+    }
+
+    /// Detaches up to `n` elements after the current one as a new list, or from the front of
+    /// the list if the cursor is on the ghost element. Past the walk over them, the elements are
+    /// relinked in one go. The cursor stays where it is.
+    pub fn drain_n(&mut self, n: usize) -> LinkedList<T> {
+        let index = self.index().map_or(0, |index| index + 1);
+        let n = n.min(self.list.len - index);
+        let Some(node) = self.inner.node.filter(|_| n != 0) else {
+            return self.list.new_sibling();
+        };
+
+        let front = node.next();
+        let back = (1..n).fold(front, |node, _| node.next());
+        let drained = unsafe { NodePtr::slice_off_as_list(front, back, n, self.list) };
+        if node.is_dummy(self.list) {
+            self.inner.index -= n;
+        }
+        self.list.notify(Event::Remove(index, n));
+        drained
+    }
+
+    /// Detaches everything before the current element as a new list, or the whole list if the
+    /// cursor is on the ghost element. The cursor ends up at index 0, or on the ghost.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        let list = unsafe { self.inner.split_before(self.list) };
+        self.list.notify(Event::Remove(0, list.len));
+        list
+    }
+
+    /// Replaces the current element with `item` in place and returns the old one. On the ghost
+    /// element there's nothing to replace, so `item` is dropped and `None` returned.
+    pub fn replace(&mut self, item: T) -> Option<T> {
+        self.current().map(|current| mem::replace(current, item))
+    }
+
+    /// Removes the element after the current one, which is the front element if the cursor is
+    /// on the ghost. The cursor stays where it is.
+    pub fn remove_next(&mut self) -> Option<T> {
+        let next = self.inner.node?.next();
+        if next.is_dummy(self.list) {
+            return None;
+        }
+
+        let on_ghost = self.index().is_none();
+        let index = self.index().map_or(0, |index| index + 1);
+        let item = unsafe { next.pop_unchecked(self.list) };
+        if on_ghost {
+            self.inner.index -= 1;
+        }
+        self.list.notify(Event::Remove(index, 1));
+        Some(item)
+    }
+
+    /// Removes the element before the current one, which is the back element if the cursor is
+    /// on the ghost. The cursor stays where it is.
+    pub fn remove_prev(&mut self) -> Option<T> {
+        let prev = self.inner.node?.prev();
+        if prev.is_dummy(self.list) {
+            return None;
+        }
+
+        let item = unsafe { prev.pop_unchecked(self.list) };
+        self.inner.index -= 1;
+        self.list.notify(Event::Remove(self.inner.index, 1));
+        Some(item)
+    }
+
+    /// Returns mutable references to the elements at the given offsets from the cursor, with
+    /// negative offsets counting backwards, or `None` if two offsets are the same or any of
+    /// them falls outside the list. Offsets don't wrap around the ghost element. Only the nodes
+    /// between the smallest and the largest offset are visited.
+    pub fn get_many_mut<const N: usize>(&mut self, offsets: [isize; N]) -> Option<[&mut T; N]> {
+        let (index, len) = (self.index().unwrap_or(self.list.len), self.list.len);
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| offsets[i]);
+
+        let sorted = order.map(|i| offsets[i]);
+        if sorted.windows(2).any(|pair| pair[0] == pair[1])
+            || sorted.first().is_some_and(|&min| min < -(index as isize))
+            || sorted
+                .last()
+                .is_some_and(|&max| max >= (len - index) as isize)
+        {
+            return None;
+        }
+
+        let mut nodes = [None; N];
+        if let (Some(&min), Some(node)) = (sorted.first(), self.inner.node) {
+            let mut node = if min < 0 {
+                (0..min.unsigned_abs()).fold(node, |node, _| node.prev())
+            } else {
+                (0..min as usize).fold(node, |node, _| node.next())
+            };
+
+            let mut offset = min;
+            for &slot in &order {
+                while offset < offsets[slot] {
+                    node = node.next();
+                    offset += 1;
+                }
+                nodes[slot] = Some(node);
+            }
+        }
+
+        // Safety: the offsets are distinct and all land on elements, so every node is an
+        // element node and is handed out at most once
+        Some(nodes.map(|node| unsafe { node.unwrap().get_mut_unchecked() }))
+    }
+
+    /// Relinks the current element at the front of the list in constant time, without
+    /// reallocating it, as an LRU cache does on every hit. The cursor stays on the element.
+    /// Does nothing on the ghost element.
+    ///
+    /// Observers see the element removed and inserted again, so a mark on it is invalidated
+    /// while marks on other elements are shifted.
+    pub fn move_current_to_front(&mut self) {
+        let (Some(index), Some(node)) = (self.index(), self.inner.node) else {
+            return;
+        };
+        if index == 0 {
+            return;
+        }
+
+        unsafe {
+            let dummy = self.list.dummy.unwrap_unchecked();
+            NodePtr::unlink(node, node, 1, self.list);
+            dummy.splice_after(node, node, 1, self.list);
+        }
+        self.inner.index = 0;
+        self.list.notify(Event::Remove(index, 1));
+        self.list.notify(Event::Insert(0));
+    }
+
+    /// Relinks the current element at the back of the list in constant time, like
+    /// [`CursorMut::move_current_to_front`].
+    pub fn move_current_to_back(&mut self) {
+        let (Some(index), Some(node)) = (self.index(), self.inner.node) else {
+            return;
+        };
+        if index == self.list.len - 1 {
+            return;
+        }
+
+        unsafe {
+            let dummy = self.list.dummy.unwrap_unchecked();
+            NodePtr::unlink(node, node, 1, self.list);
+            dummy.splice_before(node, node, 1, self.list);
+        }
+        self.inner.index = self.list.len - 1;
+        self.list.notify(Event::Remove(index, 1));
+        self.list.notify(Event::Insert(self.list.len - 1));
+    }
+
+    /// Rotates the whole list so that it starts at the current element, which keeps the cursor
+    /// at index 0. Only the dummy node is relinked, so this takes constant time. A cursor on the
+    /// ghost element leaves the list as it is.
+    pub fn rotate_to_front(&mut self) {
+        let (Some(index), Some(node)) = (self.index(), self.inner.node) else {
+            return;
+        };
+        if index == 0 {
+            return;
+        }
+
+        // Safety: the list has an element, so it has a dummy node
+        let dummy = unsafe { self.list.dummy.unwrap_unchecked() };
+        dummy.prev().link(dummy.next());
+        node.prev().link(dummy);
+        dummy.link(node);
+
+        self.inner.index = 0;
+        self.list.notify(Event::Reorder);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList, MarkError,
+    };
+
+    #[test]
+    fn test_cursor_front_back() {
+        let list: LinkedList<i32> = (0..4).collect();
+
+        let mut front = list.cursor_front();
+        assert_eq!((front.index(), front.current()), (Some(0), Some(&0)));
+        front.move_prev();
+        assert_eq!((front.index(), front.current()), (None, None));
+        assert_eq!(front.peek_prev(), Some(&3));
+
+        let mut back = list.cursor_back();
+        assert_eq!((back.index(), back.current()), (Some(3), Some(&3)));
+        back.move_next();
+        back.move_next();
+        assert_eq!((back.index(), back.current()), (Some(0), Some(&0)));
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.cursor_front().current(), None);
+        assert_eq!(empty.cursor_back().index(), None);
+    }
+
+    #[test]
+    fn test_cursor_move_n() {
+        let list: LinkedList<i32> = (0..5).collect();
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.move_next_n(3), 3);
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.move_next_n(10), 2);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.move_next_n(0), 0);
+        assert_eq!(cursor.move_next_n(2), 2);
+        assert_eq!(cursor.current(), Some(&1));
+
+        assert_eq!(cursor.move_prev_n(1), 1);
+        assert_eq!(cursor.move_prev_n(3), 1);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.move_prev_n(6), 6);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.move_prev_n(2), 2);
+        assert_eq!(cursor.current(), Some(&3));
+
+        let mut list = list;
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.move_prev_n(2), 2);
+        *cursor.current().unwrap() *= 10;
+        assert_eq!(cursor.move_next_n(usize::MAX), 3);
+        assert_eq!(list, [0, 1, 20, 3, 4]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.cursor_front().move_next_n(3), 0);
+    }
+
+    #[test]
+    fn test_cursor_seek() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+
+        let mut cursor = list.cursor_at(3);
+        cursor.seek(5);
+        assert_eq!((cursor.index(), cursor.current()), (Some(5), Some(&5)));
+        cursor.seek(0);
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.seek(9);
+        assert_eq!(cursor.current(), Some(&9));
+        cursor.seek(10);
+        assert_eq!(cursor.index(), None);
+        cursor.seek(1);
+        assert_eq!(cursor.current(), Some(&1));
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.seek(7);
+        cursor.remove_current();
+        cursor.seek(2);
+        *cursor.current().unwrap() = 20;
+        assert_eq!(list, [0, 1, 20, 3, 4, 5, 6, 8, 9]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.cursor_front_mut().seek(0);
+    }
+
+    #[test]
+    #[should_panic = "cannot place a cursor at position 4 in a list of length 3"]
+    fn test_cursor_seek_out_of_bounds() {
+        let list: LinkedList<i32> = (0..3).collect();
+        list.cursor_front().seek(4);
+    }
+
+    #[test]
+    fn test_cursor_find() {
+        let list: LinkedList<i32> = [1, 4, 6, 7, 8].into_iter().collect();
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.find(|x| x % 2 == 0), Some(&4));
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.find(|x| x % 2 == 0), Some(&4));
+        cursor.move_next();
+        assert_eq!(cursor.find_map(|x| (x % 2 == 1).then(|| x * 10)), Some(70));
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.find(|&x| x > 10), None);
+        assert_eq!(cursor.index(), None);
+
+        let mut list = list;
+        let mut cursor = list.cursor_front_mut();
+        *cursor.find(|&x| x > 5).unwrap() = 60;
+        cursor.move_next();
+        assert_eq!(
+            cursor.find_map(|x| (*x == 8).then(|| std::mem::take(x))),
+            Some(8)
+        );
+        assert_eq!(cursor.index(), Some(4));
+        assert!(cursor.find(|&x| x < 0).is_none());
+        assert_eq!(list, [1, 4, 60, 7, 0]);
+
+        assert_eq!(LinkedList::<i32>::new().cursor_front().find(|_| true), None);
+    }
+
+    #[test]
+    fn test_cursor_into_iter() {
+        let list: LinkedList<i32> = (0..6).collect();
+
+        let mut cursor = list.cursor_front();
+        cursor.find(|&x| x == 2);
+        assert!(cursor.into_iter_after().eq(&[3, 4, 5]));
+        assert!(list.cursor_at(2).into_iter_before().eq(&[1, 0]));
+        assert!(list.cursor_front().into_iter_before().eq(&[]));
+        assert_eq!(list.cursor_back().into_iter_after().len(), 0);
+
+        assert!(list.cursor_at(6).into_iter_after().eq(&list));
+        assert!(list.cursor_at(6).into_iter_before().eq(list.iter().rev()));
+        assert!(LinkedList::<i32>::new()
+            .cursor_front()
+            .into_iter_after()
+            .eq(&[]));
+
+        let mut list = list;
+        list.cursor_at_mut(3)
+            .into_iter_after()
+            .for_each(|x| *x *= 10);
+        for (i, x) in list.cursor_at_mut(3).into_iter_before().enumerate() {
+            *x = -(i as i32);
+        }
+        assert_eq!(list, [-2, -1, 0, 3, 40, 50]);
+    }
+
+    #[test]
+    fn test_cursor_split_iter_mut() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+
+        let mut cursor = list.cursor_at_mut(2);
+        let (before, after) = cursor.split_iter_mut();
+        assert_eq!((before.len(), after.len()), (2, 4));
+        for (a, b) in before.zip(after.rev()) {
+            std::mem::swap(a, b);
+        }
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        let (before, after) = cursor.split_iter_mut();
+        assert_eq!(after.count(), 0);
+        before.for_each(|x| *x += 1);
+        assert_eq!(list, [6, 5, 3, 4, 2, 1]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        let (before, after) = cursor.split_iter_mut();
+        assert_eq!((before.len(), after.len()), (0, 0));
+    }
+
+    #[test]
+    fn test_cursor_copy() {
+        let list: LinkedList<i32> = [1, 2, 3, 2, 1].into_iter().collect();
+
+        // two pointers closing in from both ends
+        let (mut front, mut back) = (list.cursor_front(), list.cursor_back());
+        while front.index() < back.index() {
+            assert_eq!(front.current(), back.current());
+            front.move_next();
+            back.move_prev();
+        }
+        assert_eq!(front.index(), Some(2));
+
+        // a copy moves independently of the original
+        let mut copy = front;
+        copy.move_next();
+        assert_eq!((front.current(), copy.current()), (Some(&3), Some(&2)));
+        assert!(front.into_iter_after().eq(&[2, 1]));
+        assert!(copy.into_iter_after().eq(&[1]));
+        assert!(front.slice_to(&copy).iter().eq(&[3]));
+    }
+
+    #[test]
+    fn test_cursor_position() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.find(|&x| x == 4);
+        let four = cursor.position();
+        cursor.move_next();
+        cursor.move_next();
+        let ghost = cursor.position();
+
+        list.push_back(6);
+        let cursor = list.cursor_from_position(four).unwrap();
+        assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&4)));
+        assert_eq!(cursor.position(), four);
+        assert_eq!(list.cursor_from_position(ghost).unwrap().index(), None);
+
+        let mut cursor = list.cursor_mut_from_position(four).unwrap();
+        cursor.remove_current();
+        assert!(list.cursor_from_position(four).is_some());
+        list.truncate(4);
+        assert!(list.cursor_from_position(four).is_none());
+        assert!(list.cursor_mut_from_position(ghost).is_some());
+    }
+
+    #[test]
+    fn test_cursor_peek_nth() {
+        let list: LinkedList<i32> = (0..5).collect();
+
+        let cursor = list.cursor_at(2);
+        assert_eq!(cursor.peek_nth(0), cursor.peek_next());
+        assert_eq!(cursor.peek_nth(1), Some(&4));
+        assert_eq!(cursor.peek_nth(2), None);
+        assert_eq!(cursor.peek_nth_back(0), cursor.peek_prev());
+        assert_eq!(cursor.peek_nth_back(1), Some(&0));
+        assert_eq!(cursor.peek_nth_back(2), None);
+        assert_eq!(cursor.index(), Some(2));
+
+        let ghost = list.cursor_at(5);
+        assert_eq!(ghost.peek_nth(4), Some(&4));
+        assert_eq!(ghost.peek_nth(5), None);
+        assert_eq!(ghost.peek_nth_back(4), Some(&0));
+        assert_eq!(LinkedList::<i32>::new().cursor_front().peek_nth(0), None);
+
+        let mut list = list;
+        let mut cursor = list.cursor_front_mut();
+        *cursor.peek_nth(2).unwrap() = 30;
+        cursor.move_next_n(4);
+        *cursor.peek_nth_back(3).unwrap() = -1;
+        assert_eq!(cursor.peek_nth(0), None);
+        assert_eq!(list, [-1, 1, 2, 30, 4]);
+    }
+
+    #[test]
+    fn test_cursor_front_back_mut() {
+        let mut list: LinkedList<i32> = (0..4).collect();
+
+        let mut front = list.cursor_front_mut();
+        *front.current().unwrap() = 10;
+        front.insert_before(-1);
+        assert_eq!(front.index(), Some(1));
+
+        let mut back = list.cursor_back_mut();
+        assert_eq!(back.remove_current(), Some(3));
+        assert_eq!(back.index(), None);
+        back.insert_after(-2);
+        assert!(list.iter().eq(&[-2, -1, 10, 1, 2]));
+
+        let mut empty = LinkedList::new();
+        let mut cursor = empty.cursor_back_mut();
+        cursor.insert_before(1);
+        cursor.insert_after(0);
+        assert_eq!(cursor.index(), None);
+        assert!(empty.iter().eq(&[0, 1]));
+    }
+
+    #[test]
+    fn test_cursor_move_current() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let front = list.front().map(|x| x as *const i32);
+        let moved = list.set_mark("moved", 2);
+        let three = list.set_mark("three", 3);
+
+        let mut cursor = list.cursor_at_mut(2);
+        cursor.move_current_to_front();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        cursor.move_current_to_back();
+        assert_eq!(cursor.index(), Some(4));
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_current_to_front();
+        cursor.move_current_to_back();
+        assert_eq!(list, [2, 1, 3, 4, 0]);
+        assert_eq!(list.back().map(|x| x as *const i32), front);
+        assert_eq!(list.mark_index(moved), Err(MarkError::Removed));
+        assert_eq!(list.mark_index(three), Ok(2));
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(2, 1),
+                Event::Insert(0),
+                Event::Remove(1, 1),
+                Event::Insert(4)
+            ]
+        );
+
+        // already in place
+        list.cursor_front_mut().move_current_to_front();
+        list.cursor_back_mut().move_current_to_back();
+        assert!(events.take().is_empty());
+        assert!(list.iter().rev().eq(&[0, 4, 3, 1, 2]));
+        list.validate();
+    }
+
+    #[test]
+    fn test_cursor_rotate_to_front() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let end = list.set_mark("end", 5);
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_prev();
+        cursor.rotate_to_front();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.peek_prev(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 4));
+        assert_eq!(list, [3, 4, 0, 1, 2]);
+        assert!(list.iter().rev().eq(&[2, 1, 0, 4, 3]));
+        assert_eq!(list.mark_index(end), Ok(5));
+
+        let mut ghost = list.cursor_front_mut();
+        ghost.move_prev();
+        ghost.rotate_to_front();
+        assert_eq!(ghost.index(), None);
+        list.cursor_front_mut().rotate_to_front();
+        assert_eq!(list, [3, 4, 0, 1, 2]);
+        list.validate();
+    }
+
+    #[test]
+    fn test_cursor_remove_current_as_list() {
+        let mut list: LinkedList<i32> = (0..4).collect();
+        let mut other: LinkedList<i32> = [10, 20].into_iter().collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        let one = cursor.remove_current_as_list().unwrap();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        let node = one.front().map(|x| x as *const i32);
+        assert_eq!(one, [1]);
+
+        let mut target = other.cursor_back_mut();
+        target.splice_before(one);
+        assert_eq!(other, [10, 1, 20]);
+        assert_eq!(other.get(1).map(|x| x as *const i32), node);
+
+        let mut ghost = list.cursor_back_mut();
+        ghost.move_next();
+        assert!(ghost.remove_current_as_list().is_none());
+        assert_eq!(list, [0, 2, 3]);
+        assert_eq!(events.take(), [Event::Remove(1, 1)]);
+        list.validate();
+        other.validate();
+    }
+
+    #[test]
+    fn test_cursor_splice() {
+        let mut list: LinkedList<i32> = [0, 5].into_iter().collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_after((1..3).collect());
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_before((3..5).collect());
+        assert_eq!(cursor.index(), Some(5));
+        assert_eq!(cursor.current(), Some(&mut 5));
+
+        // on the ghost, splicing after goes to the front and before goes to the back
+        cursor.move_next();
+        cursor.splice_after([-1].into_iter().collect());
+        cursor.splice_before([6].into_iter().collect());
+        cursor.splice_before(LinkedList::new());
+        assert_eq!(cursor.index(), None);
+        assert_eq!(list, [-1, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Splice(1, 2),
+                Event::Splice(3, 2),
+                Event::Splice(0, 1),
+                Event::Splice(7, 1)
+            ]
+        );
+
+        let mut empty = LinkedList::new();
+        empty.cursor_front_mut().splice_after((0..2).collect());
+        assert_eq!(empty, [0, 1]);
+        list.validate();
+        empty.validate();
+    }
+
+    #[test]
+    fn test_cursor_split() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let front = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        let back = cursor.split_after();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(front, [0, 1]);
+        assert_eq!(back, [4, 5, 6, 7]);
+        assert!(back.iter().rev().eq(&[7, 6, 5, 4]));
+
+        assert!(cursor.split_after().is_empty());
+        cursor.move_next();
+        let rest = cursor.split_before();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(rest, [2, 3]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(0, 2),
+                Event::Remove(2, 4),
+                Event::Remove(0, 2)
+            ]
+        );
+        assert!(list.is_empty());
+        assert!(list.cursor_front_mut().split_after().is_empty());
+        list.validate();
+        back.validate();
+    }
+
+    #[test]
+    fn test_cursor_replace() {
+        let mut list: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_back_mut();
+        let node = cursor.current().map(|x| x as *const String);
+        assert_eq!(cursor.replace("c".to_string()).as_deref(), Some("b"));
+        assert_eq!(cursor.current().map(|x| x as *const String), node);
+        cursor.move_next();
+        assert_eq!(cursor.replace("d".to_string()), None);
+        assert_eq!(list, ["a", "c"]);
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    fn test_cursor_remove_neighbours() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_at_mut(2);
+        assert_eq!(cursor.remove_next(), Some(3));
+        assert_eq!(cursor.remove_prev(), Some(1));
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.remove_prev(), Some(0));
+        assert_eq!(cursor.remove_prev(), None);
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.seek(2);
+        assert_eq!(cursor.remove_next(), None);
+        cursor.move_next();
+        assert_eq!(cursor.remove_next(), Some(2));
+        assert_eq!(cursor.remove_prev(), Some(5));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 4));
+        assert_eq!(list, [4]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(3, 1),
+                Event::Remove(1, 1),
+                Event::Remove(0, 1),
+                Event::Remove(0, 1),
+                Event::Remove(1, 1)
+            ]
+        );
+        list.validate();
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.cursor_front_mut().remove_next(), None);
+        assert_eq!(empty.cursor_back_mut().remove_prev(), None);
+    }
+
+    #[test]
+    fn test_cursor_push() {
+        let mut list: LinkedList<i32> = (1..3).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.push_front(0);
+        cursor.push_back(3);
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 2)));
+        assert_eq!((cursor.list().len(), cursor.list().back()), (4, Some(&3)));
+
+        cursor.move_next();
+        cursor.move_next();
+        cursor.push_back(4);
+        cursor.push_front(-1);
+        assert_eq!(cursor.index(), None);
+        assert!(!cursor.list().is_empty());
+        assert_eq!(list, [-1, 0, 1, 2, 3, 4]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Insert(0),
+                Event::Insert(3),
+                Event::Insert(4),
+                Event::Insert(0)
+            ]
+        );
+
+        let mut empty = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.push_back(1);
+        cursor.push_front(0);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(empty, [0, 1]);
+        empty.validate();
+    }
+
+    #[test]
+    fn test_cursor_drain_n() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut cursor = list.cursor_at_mut(2);
+        let batch = cursor.drain_n(3);
+        assert_eq!(batch, [3, 4, 5]);
+        assert!(batch.iter().rev().eq(&[5, 4, 3]));
+        assert_eq!(
+            (cursor.index(), cursor.peek_next()),
+            (Some(2), Some(&mut 6))
+        );
+        assert_eq!(cursor.drain_n(10), [6, 7, 8, 9]);
+        assert!(cursor.drain_n(1).is_empty());
+
+        cursor.move_next();
+        assert_eq!(cursor.drain_n(2), [0, 1]);
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.drain_n(0).is_empty());
+        assert_eq!(list, [2]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(3, 3),
+                Event::Remove(3, 4),
+                Event::Remove(0, 2)
+            ]
+        );
+        list.validate();
+        batch.validate();
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.cursor_front_mut().drain_n(2).is_empty());
+    }
+
+    #[test]
+    fn test_cursor_get_many_mut() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+
+        let [next, prev, current] = cursor.get_many_mut([2, -1, 0]).unwrap();
+        std::mem::swap(next, prev);
+        *current *= 10;
+        assert!(cursor.get_many_mut([1, 1]).is_none());
+        assert!(cursor.get_many_mut([-3]).is_none());
+        assert!(cursor.get_many_mut([4]).is_none());
+        assert!(cursor.get_many_mut([-2, 3]).is_some());
+        assert!(cursor.get_many_mut([]).is_some());
+
+        // from the ghost, only negative offsets reach elements
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        let [back, front] = cursor.get_many_mut([-1, -6]).unwrap();
+        std::mem::swap(back, front);
+        assert!(cursor.get_many_mut([0]).is_none());
+        assert_eq!(list, [5, 4, 20, 3, 1, 0]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert!(cursor.get_many_mut([0]).is_none());
+        assert!(cursor.get_many_mut([]).is_some());
+    }
 }
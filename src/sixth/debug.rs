@@ -0,0 +1,294 @@
+use std::fmt::{self, Debug, Display};
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "debug-alloc")]
+use super::node::NodePtr;
+use super::LinkedList;
+
+// zero is left out, as the ID of a list that hasn't been given one yet
+#[cfg(debug_assertions)]
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+// Identifies a list in diagnostics. Only tracked in debug builds, so that release builds don't
+// pay for a counter and a name per list. The ID is handed out the first time it's asked for,
+// which keeps `LinkedList::new` a `const fn`.
+#[cfg(debug_assertions)]
+pub(crate) struct DebugInfo {
+    id: AtomicU64,
+    name: Option<String>,
+}
+
+#[cfg(debug_assertions)]
+impl DebugInfo {
+    pub(crate) const fn new() -> Self {
+        Self {
+            id: AtomicU64::new(0),
+            name: None,
+        }
+    }
+
+    fn id(&self) -> u64 {
+        let id = self.id.load(Ordering::Relaxed);
+        if id != 0 {
+            return id;
+        }
+
+        // another thread may get there first through a shared reference, and then its ID wins
+        let new = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        match self
+            .id
+            .compare_exchange(0, new, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            Ok(_) => new,
+            Err(id) => id,
+        }
+    }
+}
+
+// how a list refers to itself in panic messages and debug-alloc output, e.g. `list #3 "jobs"`
+pub(crate) struct Label<'a, T>(&'a LinkedList<T>);
+
+impl<'a, T> Display for Label<'a, T> {
+    #[cfg(debug_assertions)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "list #{}", self.0.debug.id())?;
+        if let Some(name) = &self.0.debug.name {
+            write!(f, " {:?}", name)?;
+        }
+        Ok(())
+    }
+
+    // without an ID, the address of the list is the best there is
+    #[cfg(not(debug_assertions))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "list at {:p}", self.0)
+    }
+}
+
+// each node's address mapped to its element
+#[cfg(feature = "debug-alloc")]
+struct Nodes<'a, T>(&'a LinkedList<T>);
+
+#[cfg(feature = "debug-alloc")]
+impl<'a, T: Debug> Debug for Nodes<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes = unsafe { self.0.raw_iter() }.into_iter().flatten();
+        f.debug_map()
+            .entries(nodes.map(|node| (node.as_ptr(), unsafe { node.get_unchecked() })))
+            .finish()
+    }
+}
+
+/// Displays the elements of a list separated by a string, created by [`LinkedList::join`].
+pub struct Join<'a, T> {
+    list: &'a LinkedList<T>,
+    sep: &'a str,
+}
+
+impl<'a, T: Display> Display for Join<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in self.list.iter().enumerate() {
+            if index != 0 {
+                f.write_str(self.sep)?;
+            }
+            // passes the formatter on, so width and precision apply to every element
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns a value that displays the elements separated by `sep`, without building an
+    /// intermediate string, e.g. `format!("{:.1}", list.join(", "))` gives `1.0, 2.5`.
+    pub fn join<'a>(&'a self, sep: &'a str) -> Join<'a, T>
+    where
+        T: Display,
+    {
+        Join { list: self, sep }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Names the list in its `Debug` output and in diagnostics. Names are only kept in debug
+    /// builds; in release builds this does nothing.
+    pub fn set_debug_name(&mut self, name: impl Into<String>) {
+        #[cfg(debug_assertions)]
+        {
+            self.debug.name = Some(name.into());
+        }
+        #[cfg(not(debug_assertions))]
+        drop(name);
+    }
+
+    /// Returns an ID that is unique to this list for the life of the process in debug builds,
+    /// or `None` in release builds.
+    pub fn debug_id(&self) -> Option<u64> {
+        #[cfg(debug_assertions)]
+        return Some(self.debug.id());
+        #[cfg(not(debug_assertions))]
+        None
+    }
+
+    pub(crate) fn label(&self) -> Label<'_, T> {
+        Label(self)
+    }
+
+    /// Walks the whole ring and checks that every `next` link is mirrored by a `prev` link and
+    /// that the ring holds exactly `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics, naming the list, if any of the invariants doesn't hold.
+    pub fn validate(&self) {
+        let Some(dummy) = self.dummy else {
+            assert!(
+                self.len == 0,
+                "{} has no nodes but a length of {}",
+                self.label(),
+                self.len
+            );
+            return;
+        };
+
+        let mut node = dummy;
+        for index in 0..=self.len {
+            let next = node.next();
+            assert!(
+                next.prev() == node,
+                "{} has a broken back link at position {}",
+                self.label(),
+                index
+            );
+            assert!(
+                index == self.len || next != dummy,
+                "{} has {} elements but a length of {}",
+                self.label(),
+                index,
+                self.len
+            );
+            node = next;
+        }
+        assert!(
+            node == dummy,
+            "{} has more elements than its length of {}",
+            self.label(),
+            self.len
+        );
+    }
+
+    // Named lists are shown as a struct so the name and ID appear alongside the elements. Node
+    // addresses are only shown by `{:#?}` with debug-alloc, to match up with the allocation log;
+    // the alternate flag alone isn't enough, since it's passed down from any enclosing value
+    // that is pretty-printed.
+    pub(crate) fn fmt_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        T: Debug,
+    {
+        #[cfg(feature = "debug-alloc")]
+        if f.alternate() {
+            return f
+                .debug_struct("LinkedList")
+                .field("label", &format_args!("{}", self.label()))
+                .field("dummy", &self.dummy.map(NodePtr::as_ptr))
+                .field("len", &self.len)
+                .field("nodes", &Nodes(self))
+                .finish();
+        }
+
+        #[cfg(debug_assertions)]
+        if let Some(name) = &self.debug.name {
+            return f
+                .debug_struct("LinkedList")
+                .field("name", name)
+                .field("id", &self.debug.id())
+                .field("items", &self.slice(..))
+                .finish();
+        }
+
+        f.debug_list().entries(self).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::mem::ManuallyDrop;
+
+    use crate::sixth::LinkedList;
+
+    #[test]
+    fn test_validate() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.validate();
+
+        list.extend(0..10);
+        list.reverse_range(2..7);
+        list.rotate_range(.., 3);
+        list.drain_to(4..6, &mut LinkedList::new());
+        list.validate();
+
+        list.clear();
+        list.validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "has 3 elements but a length of 4")]
+    fn test_validate_len_mismatch() {
+        // never dropped, since dropping walks `len` nodes
+        let mut list = ManuallyDrop::new((0..3).collect::<LinkedList<i32>>());
+        list.len += 1;
+        list.validate();
+    }
+
+    #[test]
+    fn test_join() {
+        let list: LinkedList<f64> = [1.0, 2.5, -3.25].into_iter().collect();
+        assert_eq!(list.join(", ").to_string(), "1, 2.5, -3.25");
+        assert_eq!(format!("[{:.1}]", list.join("; ")), "[1.0; 2.5; -3.2]");
+        assert_eq!(format!("{:>4}", list.join("|")), "   1| 2.5|-3.25");
+
+        let words: LinkedList<&str> = ["just", "one"].into_iter().collect();
+        assert_eq!(words.join(" ").to_string(), "just one");
+        assert_eq!(LinkedList::<i32>::new().join(", ").to_string(), "");
+    }
+
+    #[test]
+    #[cfg(not(feature = "debug-alloc"))]
+    fn test_debug_alternate() {
+        let list: LinkedList<i32> = (0..2).collect();
+        assert_eq!(format!("{:#?}", list), "[\n    0,\n    1,\n]");
+        assert_eq!(format!("{:#?}", LinkedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    #[cfg(feature = "debug-alloc")]
+    fn test_debug_nodes() {
+        let list: LinkedList<i32> = (0..2).collect();
+        let debug = format!("{:#?}", list);
+        assert!(debug.starts_with("LinkedList {"));
+        assert!(debug.contains("len: 2,"));
+        assert!(debug.contains(": 0,") && debug.contains(": 1,"));
+        assert_eq!(format!("{:?}", list), "[0, 1]");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_debug_name() {
+        let mut a: LinkedList<i32> = (0..3).collect();
+        let b: LinkedList<i32> = LinkedList::new();
+        assert_ne!(a.debug_id(), b.debug_id());
+        assert_eq!(format!("{:?}", a), "[0, 1, 2]");
+
+        a.set_debug_name("jobs");
+        let id = a.debug_id().unwrap();
+        assert_eq!(
+            format!("{:?}", a),
+            format!(
+                "LinkedList {{ name: \"jobs\", id: {}, items: [0, 1, 2] }}",
+                id
+            )
+        );
+        assert_eq!(a.label().to_string(), format!("list #{} \"jobs\"", id));
+    }
+}
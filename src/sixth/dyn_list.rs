@@ -0,0 +1,412 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+type Link<D> = Option<NonNull<Slot<D>>>;
+
+/// A node of a [`DynList`], holding its links and the element in a single allocation.
+///
+/// A slot is created for a concrete type, `Box<Slot<D, T>>`, and coerces to `Box<Slot<D>>`
+/// wherever `T` coerces to `D`, e.g. when `D` is `dyn Trait` and `T` implements `Trait`. Slots
+/// deref to the element.
+pub struct Slot<D: ?Sized, T: ?Sized = D> {
+    prev: Link<D>,
+    next: Link<D>,
+    value: T,
+}
+
+impl<D: ?Sized, T> Slot<D, T> {
+    pub fn new(value: T) -> Box<Self> {
+        Box::new(Slot {
+            prev: None,
+            next: None,
+            value,
+        })
+    }
+
+    // slots are only ever handed out boxed, so this is the one way to get the element back
+    #[allow(clippy::boxed_local)]
+    pub fn into_inner(self: Box<Self>) -> T {
+        self.value
+    }
+}
+
+impl<D: ?Sized, T: ?Sized> Deref for Slot<D, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<D: ?Sized, T: ?Sized> DerefMut for Slot<D, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// a slot outside of a list has no links, and one inside a list is owned by it
+unsafe impl<D: ?Sized + Send, T: ?Sized + Send> Send for Slot<D, T> {}
+unsafe impl<D: ?Sized + Sync, T: ?Sized + Sync> Sync for Slot<D, T> {}
+
+/// A doubly linked list of possibly unsized elements, such as trait objects.
+///
+/// Storing `Box<dyn Trait>` in a [`LinkedList`](super::LinkedList) puts a pointer to each
+/// element in a node of its own. A `DynList<dyn Trait>` instead allocates every element inline
+/// in its node, a [`Slot`], so reaching an element from the list takes one indirection instead
+/// of two.
+///
+/// ```
+/// use too_many_linked_list::sixth::{DynList, Slot};
+///
+/// trait Stage {
+///     fn run(&self, input: i32) -> i32;
+/// }
+///
+/// struct Add(i32);
+/// struct Double;
+///
+/// impl Stage for Add {
+///     fn run(&self, input: i32) -> i32 {
+///         input + self.0
+///     }
+/// }
+///
+/// impl Stage for Double {
+///     fn run(&self, input: i32) -> i32 {
+///         input * 2
+///     }
+/// }
+///
+/// let mut pipeline: DynList<dyn Stage> = DynList::new();
+/// pipeline.push_back(Slot::new(Add(1)));
+/// pipeline.push_back(Slot::new(Double));
+///
+/// assert_eq!(pipeline.iter().fold(3, |acc, stage| stage.run(acc)), 8);
+/// ```
+pub struct DynList<D: ?Sized> {
+    head: Link<D>,
+    tail: Link<D>,
+    len: usize,
+    _phantom: PhantomData<Box<Slot<D>>>,
+}
+
+impl<D: ?Sized> Default for DynList<D> {
+    fn default() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: ?Sized> DynList<D> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn push_front(&mut self, slot: Box<Slot<D>>) {
+        let mut node = NonNull::from(Box::leak(slot));
+        unsafe {
+            node.as_mut().prev = None;
+            node.as_mut().next = self.head;
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, slot: Box<Slot<D>>) {
+        let mut node = NonNull::from(Box::leak(slot));
+        unsafe {
+            node.as_mut().prev = self.tail;
+            node.as_mut().next = None;
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
+            }
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<Box<Slot<D>>> {
+        self.head.map(|head| unsafe {
+            let mut slot = Box::from_raw(head.as_ptr());
+            self.head = slot.next.take();
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = None,
+                None => self.tail = None,
+            }
+            self.len -= 1;
+            slot
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<Box<Slot<D>>> {
+        self.tail.map(|tail| unsafe {
+            let mut slot = Box::from_raw(tail.as_ptr());
+            self.tail = slot.prev.take();
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = None,
+                None => self.head = None,
+            }
+            self.len -= 1;
+            slot
+        })
+    }
+
+    pub fn front(&self) -> Option<&D> {
+        self.head.map(|head| unsafe { &(*head.as_ptr()).value })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut D> {
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&D> {
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).value })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut D> {
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).value })
+    }
+
+    pub fn iter(&self) -> DynIter<'_, D> {
+        DynIter {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> DynIterMut<'_, D> {
+        DynIterMut {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: ?Sized> Drop for DynList<D> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<D: ?Sized> FromIterator<Box<Slot<D>>> for DynList<D> {
+    fn from_iter<I: IntoIterator<Item = Box<Slot<D>>>>(iter: I) -> Self {
+        let mut list = DynList::new();
+        for slot in iter {
+            list.push_back(slot);
+        }
+        list
+    }
+}
+
+impl<D: ?Sized + Debug> Debug for DynList<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl<D: ?Sized + Send> Send for DynList<D> {}
+unsafe impl<D: ?Sized + Sync> Sync for DynList<D> {}
+
+pub struct DynIter<'a, D: ?Sized> {
+    front: Link<D>,
+    back: Link<D>,
+    len: usize,
+    _phantom: PhantomData<&'a D>,
+}
+
+pub struct DynIterMut<'a, D: ?Sized> {
+    front: Link<D>,
+    back: Link<D>,
+    len: usize,
+    _phantom: PhantomData<&'a mut D>,
+}
+
+impl<'a, D: ?Sized> Iterator for DynIter<'a, D> {
+    type Item = &'a D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.len != 0).then(|| unsafe {
+            let node = &*self.front.unwrap_unchecked().as_ptr();
+            self.front = node.next;
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, D: ?Sized> DoubleEndedIterator for DynIter<'a, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.len != 0).then(|| unsafe {
+            let node = &*self.back.unwrap_unchecked().as_ptr();
+            self.back = node.prev;
+            self.len -= 1;
+            &node.value
+        })
+    }
+}
+
+impl<'a, D: ?Sized> ExactSizeIterator for DynIter<'a, D> {}
+
+impl<'a, D: ?Sized> Iterator for DynIterMut<'a, D> {
+    type Item = &'a mut D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.len != 0).then(|| unsafe {
+            let node = &mut *self.front.unwrap_unchecked().as_ptr();
+            self.front = node.next;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, D: ?Sized> DoubleEndedIterator for DynIterMut<'a, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.len != 0).then(|| unsafe {
+            let node = &mut *self.back.unwrap_unchecked().as_ptr();
+            self.back = node.prev;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+}
+
+impl<'a, D: ?Sized> ExactSizeIterator for DynIterMut<'a, D> {}
+
+unsafe impl<'a, D: ?Sized + Sync> Send for DynIter<'a, D> {}
+unsafe impl<'a, D: ?Sized + Sync> Sync for DynIter<'a, D> {}
+
+unsafe impl<'a, D: ?Sized + Send> Send for DynIterMut<'a, D> {}
+unsafe impl<'a, D: ?Sized + Sync> Sync for DynIterMut<'a, D> {}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, fmt::Debug, rc::Rc};
+
+    use super::{DynList, Slot};
+
+    #[test]
+    fn test_dyn_list() {
+        let mut list: DynList<dyn Debug> = DynList::new();
+        list.push_back(Slot::new(1));
+        list.push_back(Slot::new("two"));
+        list.push_front(Slot::new(0.5));
+        list.push_back(Slot::new(vec![3]));
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(format!("{:?}", list), r#"[0.5, 1, "two", [3]]"#);
+        let rev: Vec<String> = list.iter().rev().map(|x| format!("{:?}", x)).collect();
+        assert_eq!(rev, ["[3]", "\"two\"", "1", "0.5"]);
+
+        let back = list.pop_back().unwrap();
+        assert_eq!(format!("{:?}", &**back), "[3]");
+        list.push_front(back);
+        assert_eq!(format!("{:?}", list.front().unwrap()), "[3]");
+        assert_eq!(format!("{:?}", list.back().unwrap()), "\"two\"");
+    }
+
+    #[test]
+    fn test_dyn_list_mut() {
+        trait Counter {
+            fn bump(&mut self);
+            fn count(&self) -> u32;
+        }
+
+        struct By(u32, u32);
+
+        impl Counter for By {
+            fn bump(&mut self) {
+                self.0 += self.1;
+            }
+
+            fn count(&self) -> u32 {
+                self.0
+            }
+        }
+
+        let mut list: DynList<dyn Counter> =
+            (1..4).map(|step| Slot::new(By(0, step)) as _).collect();
+        list.iter_mut().for_each(|counter| counter.bump());
+        list.back_mut().unwrap().bump();
+        list.front_mut().unwrap().bump();
+        assert!(list.iter().map(|c| c.count()).eq([2, 2, 6]));
+
+        while let Some(slot) = list.pop_front() {
+            assert!(slot.count() > 0);
+        }
+        assert!(list.is_empty());
+        assert!(list.pop_back().is_none());
+    }
+
+    #[test]
+    fn test_dyn_list_sized() {
+        let mut list: DynList<String> = ["a", "b"]
+            .map(|s| Slot::new(s.to_string()))
+            .into_iter()
+            .collect();
+        list.front_mut().unwrap().push('!');
+        let slot = list.pop_front().unwrap();
+        assert_eq!(slot.into_inner(), "a!");
+        assert!(list.iter().eq(["b"]));
+    }
+
+    #[test]
+    fn test_dyn_list_drop() {
+        let drops = Rc::new(Cell::new(0));
+
+        struct Counted(Rc<Cell<usize>>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let mut list: DynList<dyn std::any::Any> = DynList::new();
+        for _ in 0..5 {
+            list.push_back(Slot::new(Counted(drops.clone())));
+        }
+        list.push_back(Slot::new(0u8));
+        drop(list.pop_front());
+        assert_eq!(drops.get(), 1);
+        drop(list);
+        assert_eq!(drops.get(), 5);
+    }
+}
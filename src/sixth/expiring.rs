@@ -0,0 +1,183 @@
+use std::{fmt::Debug, time::Instant};
+
+use super::{observer::Event, Iter, LinkedList};
+
+/// A [`LinkedList`] of elements that each carry a deadline, kept in deadline order so that
+/// expired elements are always at the front.
+///
+/// Elements with equal deadlines keep their insertion order. Deadlines are [`Instant`]s by
+/// default, but any ordered type works, e.g. ticks of a logical clock.
+pub struct ExpiringList<T, D = Instant> {
+    list: LinkedList<(D, T)>,
+}
+
+impl<T, D> Default for ExpiringList<T, D> {
+    fn default() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+}
+
+impl<T, D: Ord> ExpiringList<T, D> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn as_list(&self) -> &LinkedList<(D, T)> {
+        &self.list
+    }
+
+    pub fn into_inner(self) -> LinkedList<(D, T)> {
+        self.list
+    }
+
+    /// Inserts `item` after every element whose deadline is not later than `deadline`. The
+    /// position is found by walking from the back, so this is O(1) when deadlines mostly
+    /// arrive in order.
+    pub fn insert(&mut self, deadline: D, item: T) {
+        let mut at = self.list.init();
+        let mut index = self.list.len();
+        if let Some(iter) = unsafe { self.list.raw_iter() } {
+            for node in iter.rev() {
+                if unsafe { node.get_unchecked() }.0 <= deadline {
+                    break;
+                }
+                at = node;
+                index -= 1;
+            }
+        }
+
+        unsafe { at.insert_before((deadline, item), &mut self.list) };
+        self.list.notify(Event::Insert(index));
+    }
+
+    /// Appends `item`, for producers whose deadlines never decrease.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `deadline` is earlier than the last deadline in the list.
+    pub fn push_back(&mut self, deadline: D, item: T) {
+        if let Some((last, _)) = self.list.back() {
+            assert!(
+                *last <= deadline,
+                "deadline pushed to the back of an expiring list is earlier than the last one"
+            );
+        }
+        self.list.push_back((deadline, item));
+    }
+
+    pub fn next_deadline(&self) -> Option<&D> {
+        self.list.front().map(|(deadline, _)| deadline)
+    }
+
+    pub fn peek(&self) -> Option<(&D, &T)> {
+        self.list.front().map(|(deadline, item)| (deadline, item))
+    }
+
+    pub fn pop(&mut self) -> Option<(D, T)> {
+        self.list.pop_front()
+    }
+
+    /// Removes every element whose deadline is at or before `now`, in deadline order. Only the
+    /// expired elements and the first live one are visited.
+    pub fn expire(&mut self, now: &D) -> LinkedList<(D, T)> {
+        self.list.pop_front_while(|(deadline, _)| deadline <= now)
+    }
+
+    pub fn iter(&self) -> Iter<'_, (D, T)> {
+        self.list.iter()
+    }
+}
+
+impl<T, D: Ord> FromIterator<(D, T)> for ExpiringList<T, D> {
+    fn from_iter<I: IntoIterator<Item = (D, T)>>(iter: I) -> Self {
+        let mut list = ExpiringList::new();
+        for (deadline, item) in iter {
+            list.insert(deadline, item);
+        }
+        list
+    }
+}
+
+impl<T: Debug, D: Debug> Debug for ExpiringList<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.list.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::ExpiringList;
+
+    #[test]
+    fn test_expiring_insert() {
+        let mut list = ExpiringList::new();
+        list.insert(5, 'a');
+        list.insert(2, 'b');
+        list.insert(8, 'c');
+        list.insert(5, 'd');
+        list.insert(0, 'e');
+
+        assert!(list
+            .iter()
+            .eq(&[(0, 'e'), (2, 'b'), (5, 'a'), (5, 'd'), (8, 'c')]));
+        assert!(list
+            .as_list()
+            .iter()
+            .rev()
+            .map(|x| x.1)
+            .eq(['c', 'd', 'a', 'b', 'e']));
+        assert_eq!(list.next_deadline(), Some(&0));
+        assert_eq!(list.peek(), Some((&0, &'e')));
+    }
+
+    #[test]
+    fn test_expire() {
+        let mut list: ExpiringList<&str, u32> = [(3, "c"), (1, "a"), (7, "d"), (2, "b")]
+            .into_iter()
+            .collect();
+
+        assert!(list.expire(&0).is_empty());
+        let expired = list.expire(&2);
+        assert!(expired.iter().eq(&[(1, "a"), (2, "b")]));
+        assert_eq!(list.len(), 2);
+
+        list.push_back(9, "e");
+        assert_eq!(list.pop(), Some((3, "c")));
+        assert!(list.expire(&100).iter().map(|x| x.1).eq(["d", "e"]));
+        assert!(list.is_empty());
+        assert_eq!(list.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_expire_instants() {
+        let start = Instant::now();
+        let mut list = ExpiringList::new();
+        for secs in [30, 10, 20] {
+            list.insert(start + Duration::from_secs(secs), secs);
+        }
+
+        let expired = list.expire(&(start + Duration::from_secs(25)));
+        assert!(expired.iter().map(|x| x.1).eq([10, 20]));
+        assert_eq!(list.peek().map(|x| *x.1), Some(30));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_back_out_of_order() {
+        let mut list = ExpiringList::new();
+        list.push_back(5, ());
+        list.push_back(4, ());
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Debug;
+
+use super::{node::NodePtr, observer::Event, Iter, IterMut, LinkedList};
+
+/// A [`LinkedList`] that remembers the last node it reached by index.
+///
+/// Indexed operations walk from whichever of the front, the back or the cached "finger" is
+/// closest, so runs of accesses near each other (sequential scans, repeated edits around the
+/// same spot) cost O(distance) instead of O(n) each.
+pub struct FingerList<T> {
+    list: LinkedList<T>,
+    finger: Option<(NodePtr<T>, usize)>,
+}
+
+impl<T> Default for FingerList<T> {
+    fn default() -> Self {
+        Self {
+            list: LinkedList::new(),
+            finger: None,
+        }
+    }
+}
+
+impl<T> FingerList<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn as_list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    // the finger can't follow changes made through the list directly, so it is dropped
+    pub fn list_mut(&mut self) -> &mut LinkedList<T> {
+        self.finger = None;
+        &mut self.list
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    // `index` must be at most len
+    fn seek(&mut self, index: usize) -> NodePtr<T> {
+        let dummy = self.list.init();
+        let node = self.list.walk_to(index, self.finger).unwrap_or(dummy);
+        self.finger = Some((node, index));
+        node
+    }
+
+    fn inserted(&mut self, index: usize) {
+        if let Some((_, pos)) = self.finger.as_mut() {
+            if *pos >= index {
+                *pos += 1;
+            }
+        }
+    }
+
+    fn removed(&mut self, index: usize) {
+        self.finger = match self.finger {
+            Some((_, pos)) if pos == index => None,
+            Some((node, pos)) if pos > index => Some((node, pos - 1)),
+            finger => finger,
+        };
+    }
+
+    pub fn get(&mut self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let node = self.seek(index);
+        Some(unsafe { node.get_unchecked() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let node = self.seek(index);
+        Some(unsafe { node.get_mut_unchecked() })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(
+            index <= self.len(),
+            "insertion index {} out of bounds for a list of length {}",
+            index,
+            self.len()
+        );
+
+        let at = self.seek(index);
+        unsafe { at.insert_before(item, &mut self.list) };
+        self.finger = Some((at.prev(), index));
+        self.list.notify(Event::Insert(index));
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let node = self.seek(index);
+        let next = node.next();
+        let item = unsafe { node.pop_unchecked(&mut self.list) };
+        self.finger = Some((next, index));
+        self.list.notify(Event::Remove(index, 1));
+        Some(item)
+    }
+
+    pub fn push_front(&mut self, item: T) {
+        self.list.push_front(item);
+        self.inserted(0);
+    }
+
+    pub fn push_back(&mut self, item: T) {
+        self.list.push_back(item);
+        self.inserted(self.len() - 1);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let item = self.list.pop_front()?;
+        self.removed(0);
+        Some(item)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let item = self.list.pop_back()?;
+        self.removed(self.len());
+        Some(item)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+}
+
+impl<T> From<LinkedList<T>> for FingerList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self { list, finger: None }
+    }
+}
+
+impl<T> FromIterator<T> for FingerList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LinkedList::from_iter(iter).into()
+    }
+}
+
+impl<T: Debug> Debug for FingerList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.list.fmt(f)
+    }
+}
+
+unsafe impl<T: Send> Send for FingerList<T> {}
+unsafe impl<T: Sync> Sync for FingerList<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::FingerList;
+
+    #[test]
+    fn test_finger_get() {
+        let mut list: FingerList<i32> = (0..100).collect();
+
+        for i in 0..100 {
+            assert_eq!(list.get(i), Some(&(i as i32)));
+        }
+        for i in (0..100).rev() {
+            *list.get_mut(i).unwrap() += 1;
+        }
+        assert_eq!(list.get(100), None);
+        assert!(list.iter().copied().eq(1..101));
+    }
+
+    #[test]
+    fn test_finger_insert_remove() {
+        let mut list = FingerList::new();
+        list.insert(0, 1);
+        list.insert(1, 3);
+        list.insert(1, 2);
+        list.insert(0, 0);
+        list.insert(4, 4);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4]));
+
+        assert_eq!(list.get(2), Some(&2));
+        assert_eq!(list.remove(2), Some(2));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.remove(3), Some(4));
+        assert_eq!(list.remove(3), None);
+        assert!(list.iter().eq(&[0, 1, 3]));
+        assert!(list.iter().rev().eq(&[3, 1, 0]));
+    }
+
+    #[test]
+    fn test_finger_push_pop() {
+        let mut list: FingerList<i32> = (0..5).collect();
+
+        assert_eq!(list.get(3), Some(&3));
+        list.push_front(-1);
+        assert_eq!(list.get(4), Some(&3));
+        list.push_back(5);
+        assert_eq!(list.get(4), Some(&3));
+        assert_eq!(list.pop_front(), Some(-1));
+        assert_eq!(list.get(3), Some(&3));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.get(3), Some(&3));
+
+        // the finger is parked on the end after removing the last element
+        assert_eq!(list.remove(3), Some(3));
+        list.push_back(7);
+        assert_eq!(list.get(3), Some(&7));
+        assert_eq!(list.len(), 4);
+
+        list.list_mut().push_front(9);
+        assert_eq!(list.get(0), Some(&9));
+        assert!(list.into_inner().iter().eq(&[9, 0, 1, 2, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_finger_insert_out_of_bounds() {
+        let mut list: FingerList<i32> = (0..3).collect();
+        list.insert(4, 0);
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+use super::{node::NodePtr, observer::Event, CursorMut, LinkedList};
+
+/// A handle to an element of a [`LinkedList`], from [`LinkedList::push_front_handle`],
+/// [`LinkedList::push_back_handle`] or [`CursorMut::handle`], which reaches the element in
+/// constant time however far it is from either end of the list.
+///
+/// A handle points at the element's node, so it stays valid while the node is relinked, e.g.
+/// when the list is sorted or appended to another one. It doesn't keep the element alive or
+/// notice it being removed, though, which is why the methods taking a handle are unsafe.
+///
+/// Getting at the element is always constant time, but while the list has an observer or marks,
+/// [`LinkedList::remove_handle`] and [`LinkedList::move_handle_to_front`] take O(n), as they walk
+/// the list to find the index to report.
+pub struct NodeHandle<T>(NodePtr<T>);
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T> Hash for NodeHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state)
+    }
+}
+
+impl<T> Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeHandle").field(&self.0.as_ptr()).finish()
+    }
+}
+
+unsafe impl<T: Send> Send for NodeHandle<T> {}
+unsafe impl<T: Sync> Sync for NodeHandle<T> {}
+
+impl<T> LinkedList<T> {
+    pub fn push_front_handle(&mut self, item: T) -> NodeHandle<T> {
+        self.push_front(item);
+        // Safety: the list was just pushed onto
+        NodeHandle(unsafe { self.front_node().unwrap_unchecked() })
+    }
+
+    pub fn push_back_handle(&mut self, item: T) -> NodeHandle<T> {
+        self.push_back(item);
+        // Safety: the list was just pushed onto
+        NodeHandle(unsafe { self.back_node().unwrap_unchecked() })
+    }
+
+    // Observers and marks are told about removals by index, which has to be found by walking
+    // the list. Without either, nothing needs it. A handle that isn't in the list breaks the
+    // callers' safety contract, so there's no index to fall back on.
+    fn handle_index(&self, handle: NodeHandle<T>) -> usize {
+        if !self.has_observer() && self.marks.is_empty() {
+            return 0;
+        }
+        unsafe { self.raw_iter() }
+            .and_then(|mut iter| iter.position(|node| node == handle.0))
+            .unwrap_or_else(|| unreachable!("{} doesn't hold the handle's element", self.label()))
+    }
+
+    /// # Safety
+    ///
+    /// `handle` must refer to an element that is in this list, that is, one that hasn't been
+    /// removed since the handle was made, and that hasn't been moved to another list.
+    pub unsafe fn get_handle(&self, handle: NodeHandle<T>) -> &T {
+        handle.0.get_unchecked()
+    }
+
+    /// # Safety
+    ///
+    /// `handle` must refer to an element that is in this list, as for
+    /// [`LinkedList::get_handle`].
+    pub unsafe fn get_handle_mut(&mut self, handle: NodeHandle<T>) -> &mut T {
+        handle.0.get_mut_unchecked()
+    }
+
+    /// Removes the element `handle` refers to in constant time, unless the list has an observer
+    /// or marks, which have to be told the element's index.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must refer to an element that is in this list, as for
+    /// [`LinkedList::get_handle`]. The handle is no longer valid afterwards.
+    pub unsafe fn remove_handle(&mut self, handle: NodeHandle<T>) -> T {
+        let index = self.handle_index(handle);
+        let item = handle.0.pop_unchecked(self);
+        self.notify(Event::Remove(index, 1));
+        item
+    }
+
+    /// Relinks the element `handle` refers to at the front of the list, as an LRU cache does on
+    /// every hit. Observers see the element removed and inserted again, which means finding its
+    /// index; without observers or marks this takes constant time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must refer to an element that is in this list, as for
+    /// [`LinkedList::get_handle`].
+    pub unsafe fn move_handle_to_front(&mut self, handle: NodeHandle<T>) {
+        let dummy = self.dummy.unwrap_unchecked();
+        if dummy.next() == handle.0 {
+            return;
+        }
+
+        let index = self.handle_index(handle);
+        NodePtr::unlink(handle.0, handle.0, 1, self);
+        dummy.splice_after(handle.0, handle.0, 1, self);
+        self.notify(Event::Remove(index, 1));
+        self.notify(Event::Insert(0));
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns a handle to the current element, or `None` on the ghost element.
+    pub fn handle(&self) -> Option<NodeHandle<T>> {
+        self.index()?;
+        self.inner.node.map(NodeHandle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
+
+    #[test]
+    fn test_handles() {
+        let mut list = LinkedList::new();
+        let b = list.push_back_handle('b');
+        let a = list.push_front_handle('a');
+        list.push_back('c');
+        let c = list.cursor_back_mut().handle().unwrap();
+        assert!(list.cursor_at_mut(3).handle().is_none());
+
+        unsafe {
+            assert_eq!((*list.get_handle(a), *list.get_handle(c)), ('a', 'c'));
+            *list.get_handle_mut(b) = 'B';
+            list.move_handle_to_front(c);
+            list.move_handle_to_front(c);
+            assert_eq!(list, ['c', 'a', 'B']);
+
+            // handles follow their nodes through a sort
+            list.sort();
+            assert_eq!(list.remove_handle(a), 'a');
+            assert_eq!(list, ['B', 'c']);
+            assert_eq!(list.remove_handle(c), 'c');
+            assert_eq!(list.remove_handle(b), 'B');
+        }
+        assert!(list.is_empty());
+        list.validate();
+    }
+
+    #[test]
+    fn test_handle_events() {
+        let mut list: LinkedList<i32> = (0..4).collect();
+        let handle = list.push_back_handle(4);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let front = list.set_mark("front", 0);
+
+        unsafe {
+            list.move_handle_to_front(handle);
+            assert_eq!(list.mark_index(front), Ok(1));
+            list.push_back(5);
+            let five = list.cursor_back_mut().handle().unwrap();
+            assert_eq!(list.remove_handle(five), 5);
+            assert_eq!(list.remove_handle(handle), 4);
+            assert_eq!(list.mark_index(front), Ok(0));
+        }
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(4, 1),
+                Event::Insert(0),
+                Event::Insert(5),
+                Event::Remove(5, 1),
+                Event::Remove(0, 1)
+            ]
+        );
+        assert_eq!(list, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_handle_lru() {
+        const CAPACITY: usize = 3;
+
+        let mut order = LinkedList::new();
+        let mut cache = HashMap::new();
+        for key in [1, 2, 3, 1, 4, 2, 5] {
+            if let Some(&handle) = cache.get(&key) {
+                unsafe { order.move_handle_to_front(handle) };
+                continue;
+            }
+            if order.len() == CAPACITY {
+                let evicted = order.pop_back().unwrap();
+                cache.remove(&evicted);
+            }
+            cache.insert(key, order.push_front_handle(key));
+        }
+
+        assert_eq!(order, [5, 2, 4]);
+        let mut keys: Vec<_> = cache.into_keys().collect();
+        keys.sort();
+        assert_eq!(keys, [2, 4, 5]);
+    }
+}
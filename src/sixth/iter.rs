@@ -1,6 +1,11 @@
-use std::marker::PhantomData;
+use std::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::Range,
+};
 
-use super::{node::NodePtr, LinkedList};
+use super::{node::NodePtr, observer::Event, LinkedList};
 
 #[derive(Debug)]
 pub(crate) struct RawIter<T> {
@@ -9,6 +14,14 @@ pub(crate) struct RawIter<T> {
     len: usize,
 }
 
+impl<T> Clone for RawIter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RawIter<T> {}
+
 impl<T> RawIter<T> {
     pub(crate) fn new(front: NodePtr<T>, back: NodePtr<T>, len: usize) -> Self {
         Self { front, back, len }
@@ -80,18 +93,21 @@ pub struct IterMut<'a, T> {
 
 pub struct DrainFilter<'a, T, F> {
     inner: Option<RawIter<T>>,
-    retained: usize,
+    retained_front: usize,
+    retained_back: usize,
     pred: F,
     list: &'a mut LinkedList<T>,
 }
 
 impl<'a, T, F> DrainFilter<'a, T, F> {
-    pub(crate) fn new(list: &'a mut LinkedList<T>, pred: F) -> Self {
-        let inner = unsafe { list.raw_iter() };
+    // only visits the elements in `range`, which must already be resolved
+    pub(crate) fn new(list: &'a mut LinkedList<T>, range: Range<usize>, pred: F) -> Self {
+        let inner = list.raw_slice(range.clone());
 
         Self {
             inner,
-            retained: 0,
+            retained_front: range.start,
+            retained_back: list.len - range.end,
             pred,
             list,
         }
@@ -102,6 +118,40 @@ pub struct IntoIter<T> {
     inner: LinkedList<T>,
 }
 
+/// The elements of a range of a list, detached from it up front by [`LinkedList::drain`].
+/// Whatever the iterator doesn't yield is dropped along with it.
+pub struct Drain<'a, T> {
+    inner: LinkedList<T>,
+    _phantom: PhantomData<&'a mut LinkedList<T>>,
+}
+
+impl<'a, T> Drain<'a, T> {
+    pub(crate) fn new(inner: LinkedList<T>) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn from_raw(inner: Option<RawIter<T>>) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn from_raw(inner: Option<RawIter<T>>) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -145,11 +195,11 @@ impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for DrainFilter<'a, T, F> {
             };
 
             if to_remove {
-                unsafe {
-                    return Some(ptr.pop_unchecked(self.list));
-                }
+                let item = unsafe { ptr.pop_unchecked(self.list) };
+                self.list.notify(Event::Remove(self.retained_front, 1));
+                return Some(item);
             } else {
-                self.retained = self.retained.saturating_add(1);
+                self.retained_front = self.retained_front.saturating_add(1);
             }
         }
 
@@ -157,7 +207,8 @@ impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for DrainFilter<'a, T, F> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.list.len - self.retained))
+        let retained = self.retained_front + self.retained_back;
+        (0, Some(self.list.len - retained))
     }
 }
 
@@ -200,11 +251,12 @@ impl<'a, T, F: FnMut(&mut T) -> bool> DoubleEndedIterator for DrainFilter<'a, T,
             };
 
             if to_remove {
-                unsafe {
-                    return Some(ptr.pop_unchecked(self.list));
-                }
+                let item = unsafe { ptr.pop_unchecked(self.list) };
+                let index = self.list.len - self.retained_back;
+                self.list.notify(Event::Remove(index, 1));
+                return Some(item);
             } else {
-                self.retained = self.retained.saturating_add(1);
+                self.retained_back = self.retained_back.saturating_add(1);
             }
         }
 
@@ -218,6 +270,24 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.len, Some(self.inner.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.pop_back()
+    }
+}
+
 impl<'a, T> ExactSizeIterator for Iter<'a, T> {
     fn len(&self) -> usize {
         self.inner.as_ref().map_or(0, RawIter::len)
@@ -236,6 +306,94 @@ impl<T> ExactSizeIterator for IntoIter<T> {
     }
 }
 
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self::from_raw(self.inner)
+    }
+}
+
+impl<T: Clone> Clone for IntoIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Default for Iter<'a, T> {
+    fn default() -> Self {
+        Self::from_raw(None)
+    }
+}
+
+impl<'a, T> Default for IterMut<'a, T> {
+    fn default() -> Self {
+        Self::from_raw(None)
+    }
+}
+
+impl<T> Default for IntoIter<T> {
+    fn default() -> Self {
+        Self {
+            inner: LinkedList::new(),
+        }
+    }
+}
+
+// the elements an iterator has yet to yield, for its `Debug` output
+struct Remaining<'a, T>(Option<RawIter<T>>, PhantomData<&'a T>);
+
+impl<'a, T: Debug> Debug for Remaining<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(Iter::from_raw(self.0)).finish()
+    }
+}
+
+impl<'a, T: Debug> Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&Remaining::<T>(self.inner, PhantomData))
+            .finish()
+    }
+}
+
+impl<'a, T: Debug> Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut")
+            .field(&Remaining::<T>(self.inner, PhantomData))
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.inner).finish()
+    }
+}
+
+// Every list iterator knows its exact remaining length, which lets `collect` and `extend` into
+// a `Vec` reserve once and write without checking capacity.
+#[cfg(feature = "nightly")]
+unsafe impl<'a, T> std::iter::TrustedLen for Iter<'a, T> {}
+#[cfg(feature = "nightly")]
+unsafe impl<'a, T> std::iter::TrustedLen for IterMut<'a, T> {}
+#[cfg(feature = "nightly")]
+unsafe impl<T> std::iter::TrustedLen for IntoIter<T> {}
+#[cfg(feature = "nightly")]
+unsafe impl<'a, T> std::iter::TrustedLen for Drain<'a, T> {}
+
 impl<T> IntoIterator for LinkedList<T> {
     type Item = T;
 
@@ -274,7 +432,7 @@ impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
     }
 }
 
-unsafe impl<'a, T: Send> Send for Iter<'a, T> {}
+unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
 unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
 
 unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
@@ -287,3 +445,16 @@ unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
 /// ```
 #[allow(unused)]
 fn iter_mut_invariant() {}
+
+/// ```compile_fail
+/// use std::cell::Cell;
+///
+/// use too_many_linked_list::sixth::LinkedList;
+///
+/// fn is_send<T: Send>(_: T) {}
+///
+/// let list: LinkedList<Cell<i32>> = LinkedList::new();
+/// is_send(list.iter());
+/// ```
+#[allow(unused)]
+fn iter_send_requires_sync() {}
@@ -0,0 +1,259 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use super::{observer::Event, Cursor, CursorMut, LinkedList};
+
+// shared by every list, so that a mark from one list is never mistaken for one of another's
+static NEXT_MARK: AtomicU64 = AtomicU64::new(0);
+
+/// A handle to a named position in a [`LinkedList`], created by [`LinkedList::set_mark`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mark(u64);
+
+/// Why a mark no longer refers to a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkError {
+    /// The marked element was removed from the list.
+    Removed,
+    /// The list was cleared.
+    Cleared,
+    /// The list was rearranged, e.g. sorted, so the marked element's position isn't known.
+    Reordered,
+    /// The mark belongs to another list, or was removed with [`LinkedList::remove_mark`].
+    Unknown,
+}
+
+impl Display for MarkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MarkError::Removed => "the marked element was removed",
+            MarkError::Cleared => "the list was cleared",
+            MarkError::Reordered => "the list was reordered",
+            MarkError::Unknown => "the mark is not in this list",
+        })
+    }
+}
+
+impl Error for MarkError {}
+
+// The marks of a list, kept up to date from the same events observers see. Invalidated marks
+// stay, along with the reason, until they're removed or their name is reused.
+#[derive(Default)]
+pub(crate) struct Marks(Vec<Entry>);
+
+struct Entry {
+    mark: Mark,
+    name: String,
+    pos: Result<usize, MarkError>,
+}
+
+impl Marks {
+    pub(crate) const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    // `len` is the length of the list after the event
+    pub(crate) fn adjust(&mut self, event: Event, len: usize) {
+        for entry in &mut self.0 {
+            let Ok(pos) = entry.pos else {
+                continue;
+            };
+
+            entry.pos = match event {
+                Event::Insert(index) if index <= pos => Ok(pos + 1),
+                Event::Splice(index, n) if index <= pos => Ok(pos + n),
+                Event::Remove(index, n) if index <= pos && pos < index + n => {
+                    Err(MarkError::Removed)
+                }
+                Event::Remove(index, n) if index <= pos => Ok(pos - n),
+                Event::Clear(n) if pos == n => Ok(0),
+                Event::Clear(_) => Err(MarkError::Cleared),
+                Event::Reorder if pos != len => Err(MarkError::Reordered),
+                _ => Ok(pos),
+            };
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn get(&self, mark: Mark) -> Result<&Entry, MarkError> {
+        self.0
+            .iter()
+            .find(|entry| entry.mark == mark)
+            .ok_or(MarkError::Unknown)
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Marks position `index` under `name` and returns a handle to it. The mark follows its
+    /// element as elements are inserted and removed around it, until the element itself is
+    /// removed or the list is cleared or reordered. Position `len` marks the end of the list,
+    /// which stays at the end.
+    ///
+    /// Setting a name that's already in use moves that mark, and returns the same handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn set_mark(&mut self, name: impl Into<String>, index: usize) -> Mark {
+        assert!(
+            index <= self.len,
+            "cannot mark position {} in a list of length {}",
+            index,
+            self.len
+        );
+
+        let name = name.into();
+        if let Some(entry) = self.marks.0.iter_mut().find(|entry| entry.name == name) {
+            entry.pos = Ok(index);
+            return entry.mark;
+        }
+
+        let mark = Mark(NEXT_MARK.fetch_add(1, Ordering::Relaxed));
+        self.marks.0.push(Entry {
+            mark,
+            name,
+            pos: Ok(index),
+        });
+        mark
+    }
+
+    pub fn find_mark(&self, name: &str) -> Option<Mark> {
+        self.marks
+            .0
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| entry.mark)
+    }
+
+    pub fn mark_name(&self, mark: Mark) -> Option<&str> {
+        self.marks.get(mark).ok().map(|entry| entry.name.as_str())
+    }
+
+    /// Returns the current position of `mark`, or why it no longer has one.
+    pub fn mark_index(&self, mark: Mark) -> Result<usize, MarkError> {
+        self.marks.get(mark)?.pos
+    }
+
+    /// Forgets `mark`, returning its last position.
+    pub fn remove_mark(&mut self, mark: Mark) -> Result<usize, MarkError> {
+        let index = self
+            .marks
+            .0
+            .iter()
+            .position(|entry| entry.mark == mark)
+            .ok_or(MarkError::Unknown)?;
+        self.marks.0.remove(index).pos
+    }
+
+    /// Returns a cursor at the position of `mark`, which is the ghost element if the mark is at
+    /// the end of the list.
+    pub fn cursor_at_mark(&self, mark: Mark) -> Result<Cursor<'_, T>, MarkError> {
+        let index = self.mark_index(mark)?;
+        Ok(Cursor::from_raw(self.raw_cursor_at(index), self))
+    }
+
+    pub fn cursor_mut_at_mark(&mut self, mark: Mark) -> Result<CursorMut<'_, T>, MarkError> {
+        let index = self.mark_index(mark)?;
+        Ok(CursorMut::from_raw(self.raw_cursor_at(index), self))
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Marks the cursor's position, as [`LinkedList::set_mark`] does. A cursor on the ghost
+    /// element marks the end of the list.
+    pub fn set_mark(&mut self, name: impl Into<String>) -> Mark {
+        let index = self.index().unwrap_or(self.list.len);
+        self.list.set_mark(name, index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::{LinkedList, MarkError};
+
+    #[test]
+    fn test_marks_follow_edits() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+        let three = list.set_mark("three", 3);
+        let end = list.set_mark("end", 10);
+
+        list.push_front(-1);
+        list.extend_from_slice(&[10, 11]);
+        assert_eq!(list.mark_index(three), Ok(4));
+        assert_eq!(list.mark_index(end), Ok(13));
+
+        list.drain_to(0..2, &mut LinkedList::new());
+        list.pop_back();
+        assert_eq!(list.mark_index(three), Ok(2));
+        assert_eq!(list.cursor_at_mark(three).unwrap().current(), Some(&3));
+        assert_eq!(list.cursor_at_mark(end).unwrap().current(), None);
+
+        let mut cursor = list.cursor_mut_at_mark(three).unwrap();
+        cursor.insert_before(100);
+        cursor.insert_after(200);
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(list.mark_index(three), Err(MarkError::Removed));
+        assert_eq!(list.mark_index(end), Ok(list.len()));
+
+        assert_eq!(list.find_mark("three"), Some(three));
+        assert_eq!(list.mark_name(end), Some("end"));
+        assert_eq!(list.remove_mark(three), Err(MarkError::Removed));
+        assert_eq!(list.find_mark("three"), None);
+        assert_eq!(list.mark_index(three), Err(MarkError::Unknown));
+    }
+
+    #[test]
+    fn test_marks_invalidated() {
+        let mut list: LinkedList<i32> = [5, 2, 8, 1].into_iter().collect();
+        let first = list.set_mark("first", 0);
+        let end = list.set_mark("end", 4);
+        list.sort_insertion();
+        assert_eq!(list.mark_index(first), Err(MarkError::Reordered));
+        assert_eq!(list.mark_index(end), Ok(4));
+
+        let first = list.set_mark("first", 0);
+        list.clear();
+        assert_eq!(list.mark_index(first), Err(MarkError::Cleared));
+        assert_eq!(list.mark_index(end), Ok(0));
+        list.push_back(1);
+        assert_eq!(list.mark_index(end), Ok(1));
+
+        let other: LinkedList<i32> = LinkedList::new();
+        assert_eq!(other.mark_index(end), Err(MarkError::Unknown));
+        assert!(other.cursor_at_mark(end).is_err());
+        assert_eq!(MarkError::Cleared.to_string(), "the list was cleared");
+    }
+
+    #[test]
+    fn test_marks_from_cursor() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let end = list.set_mark("end", 0);
+
+        let mut ghost = list.cursor_mut_at_mark(end).unwrap();
+        ghost.insert_before(1);
+        ghost.insert_before(2);
+        ghost.move_prev();
+        let two = ghost.set_mark("two");
+        ghost.insert_before(3);
+        assert!(list.iter().eq(&[1, 3, 2]));
+        assert_eq!(list.mark_index(two), Ok(2));
+        assert_eq!(list.mark_index(end), Ok(3));
+
+        // reusing a name moves the mark
+        assert_eq!(list.set_mark("two", 0), two);
+        assert_eq!(list.mark_index(two), Ok(0));
+    }
+
+    #[test]
+    #[should_panic = "cannot mark position 4 in a list of length 3"]
+    fn test_mark_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.set_mark("past the end", 4);
+    }
+}
@@ -1,37 +1,216 @@
-use std::{fmt::Debug, hash::Hash, marker::PhantomData};
-
-use self::node::NodePtr;
-
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
+    sync::Arc,
+};
+
+#[cfg(debug_assertions)]
+use self::debug::DebugInfo;
+use self::{
+    cursor::RawCursor,
+    iter::RawIter,
+    marks::Marks,
+    node::NodePtr,
+    observer::{BoxedObserver, Event},
+    pool::RawPool,
+};
+
+mod batch;
+mod compare;
+#[cfg(feature = "std-compat")]
+pub mod compat;
+mod convert;
 mod cursor;
+mod debug;
+mod dyn_list;
+mod expiring;
+mod finger;
+mod handle;
 mod iter;
+mod marks;
 mod node;
-
-pub use cursor::{Cursor, CursorMut};
-pub use iter::{DrainFilter, IntoIter, Iter, IterMut};
+mod observer;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod partition;
+mod pool;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+mod search;
+mod set;
+mod slice;
+mod sort;
+mod split;
+mod walk;
+mod zip;
+
+pub use batch::BatchEdit;
+pub use cursor::{Cursor, CursorMut, CursorPosition};
+pub use debug::Join;
+pub use dyn_list::{DynIter, DynIterMut, DynList, Slot};
+pub use expiring::ExpiringList;
+pub use finger::FingerList;
+pub use handle::NodeHandle;
+pub use iter::{Drain, DrainFilter, IntoIter, Iter, IterMut};
+pub use marks::{Mark, MarkError};
+pub use observer::ListObserver;
+pub use pool::NodePool;
+pub use slice::{ListSlice, ListSliceMut};
+pub use split::{ChunkBy, Chunks, Parts, SplitInclusive};
+pub use walk::{Pairs, Visit};
 
 pub struct LinkedList<T> {
     pub(crate) dummy: Option<NodePtr<T>>,
     pub(crate) len: usize,
+    pub(crate) observer: Option<BoxedObserver>,
+    pub(crate) marks: Marks,
+    pub(crate) pool: Option<Arc<RawPool>>,
+    #[cfg(debug_assertions)]
+    pub(crate) debug: DebugInfo,
     _phantom: PhantomData<T>,
 }
 
 impl<T> Default for LinkedList<T> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub const fn new() -> Self {
         Self {
             dummy: None,
             len: 0,
+            observer: None,
+            marks: Marks::new(),
+            pool: None,
+            #[cfg(debug_assertions)]
+            debug: DebugInfo::new(),
             _phantom: PhantomData,
         }
     }
-}
 
-impl<T> LinkedList<T> {
-    pub fn new() -> Self {
-        Default::default()
+    pub(crate) fn init(&mut self) -> NodePtr<T> {
+        if let Some(dummy) = self.dummy {
+            return dummy;
+        }
+
+        let dummy = NodePtr::dummy(self.pool());
+        #[cfg(feature = "debug-alloc")]
+        println!(
+            "Dummy node {:p} belongs to {}\n",
+            dummy.as_ptr(),
+            self.label()
+        );
+        *self.dummy.insert(dummy)
     }
 
-    pub(crate) fn init(&mut self) -> NodePtr<T> {
-        *self.dummy.get_or_insert_with(|| NodePtr::dummy())
+    pub(crate) fn front_node(&self) -> Option<NodePtr<T>> {
+        let dummy = self.dummy?;
+        (self.len != 0).then(|| dummy.next())
+    }
+
+    pub(crate) fn back_node(&self) -> Option<NodePtr<T>> {
+        let dummy = self.dummy?;
+        (self.len != 0).then(|| dummy.prev())
+    }
+
+    // Walks to position `index`, where positions 0..len are the elements and position len is the
+    // dummy node, starting from whichever of the dummy node or `hint` (a node and its position)
+    // is the fewest steps away in either direction. `index` must be at most len.
+    pub(crate) fn walk_to(
+        &self,
+        index: usize,
+        hint: Option<(NodePtr<T>, usize)>,
+    ) -> Option<NodePtr<T>> {
+        let dummy = self.dummy?;
+        debug_assert!(index <= self.len);
+
+        let ring = self.len + 1;
+        let (start, forward, backward) = [Some((dummy, self.len)), hint]
+            .into_iter()
+            .flatten()
+            .map(|(node, pos)| {
+                let forward = (index + ring - pos) % ring;
+                (node, forward, (ring - forward) % ring)
+            })
+            .min_by_key(|&(_, forward, backward)| forward.min(backward))?;
+
+        let node = if forward <= backward {
+            (0..forward).fold(start, |node, _| node.next())
+        } else {
+            (0..backward).fold(start, |node, _| node.prev())
+        };
+        Some(node)
+    }
+
+    // moves the nodes front..=back out of `other` and links them in before `at`
+    // `at` must be a node of self, and front..=back must be `len` nodes of other
+    pub(crate) unsafe fn transfer(
+        &mut self,
+        at: NodePtr<T>,
+        other: &mut LinkedList<T>,
+        front: NodePtr<T>,
+        back: NodePtr<T>,
+        len: usize,
+    ) {
+        NodePtr::unlink(front, back, len, other);
+        at.splice_before(front, back, len, self);
+    }
+
+    // moves every element of `other` in before `at`, without notifying observers
+    // `at` must be a node of self
+    pub(crate) unsafe fn transfer_all(&mut self, at: NodePtr<T>, other: &mut LinkedList<T>) {
+        if let (Some(front), Some(back)) = (other.front_node(), other.back_node()) {
+            let len = other.len;
+            self.transfer(at, other, front, back, len);
+        }
+    }
+
+    // moves every element of `other` to the back of self
+    pub(crate) fn splice_back(&mut self, other: &mut LinkedList<T>) {
+        let (index, len) = (self.len, other.len);
+        let dummy = self.init();
+        unsafe { self.transfer_all(dummy, other) };
+
+        other.notify(Event::Clear(len));
+        self.notify(Event::Splice(index, len));
+    }
+
+    /// Moves every element of `other` to the back of the list in constant time, by relinking,
+    /// leaving `other` empty.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        self.splice_back(other);
+    }
+
+    /// Moves every element of `other` to the front of the list in constant time, by relinking,
+    /// leaving `other` empty.
+    pub fn prepend(&mut self, other: &mut LinkedList<T>) {
+        let len = other.len;
+        let dummy = self.init();
+        unsafe { self.transfer_all(dummy.next(), other) };
+
+        other.notify(Event::Clear(len));
+        self.notify(Event::Splice(0, len));
+    }
+
+    /// Pushes the items onto the front of the list, keeping the order they come in. They're
+    /// collected into a run of their own first, which is then spliced on in one go.
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut items = self.new_sibling();
+        items.extend(iter);
+        self.prepend(&mut items);
+    }
+
+    /// Joins the lists end to end, splicing each one on in constant time, so the cost is
+    /// linear in the number of lists rather than the number of elements.
+    pub fn concat<I: IntoIterator<Item = LinkedList<T>>>(lists: I) -> Self {
+        let mut res = LinkedList::new();
+        res.extend(lists);
+        res
     }
 
     pub fn len(&self) -> usize {
@@ -42,14 +221,52 @@ impl<T> LinkedList<T> {
         self.len() == 0
     }
 
+    /// Drops every element. The nodes are detached and observers told before any element is
+    /// dropped, so a panicking destructor still leaves an empty list behind that observers
+    /// know about.
     pub fn clear(&mut self) {
-        unsafe {
-            if let Some(iter) = self.raw_iter() {
-                iter.for_each(|ptr| {
-                    ptr.dealloc_raw();
-                })
-            }
+        let Some(iter) = (unsafe { self.raw_iter() }) else {
+            return;
+        };
+
+        if let Some(dummy) = self.dummy {
+            dummy.link(dummy);
+        }
+        let len = self.len;
+        self.len = 0;
+        self.notify(Event::Clear(len));
+
+        DeallocChain {
+            iter,
+            pool: self.pool(),
+        }
+        .dealloc_all();
+    }
+
+    /// Drops every element from index `len` onward. Does nothing if the list is no longer than
+    /// `len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        if let Some(front) = self.walk_to(len, None) {
+            drop(unsafe { self.detach_back(front, self.len - len) });
+        }
+    }
+
+    /// Truncates the list to `new_len`, or appends elements generated by `f` until it's that
+    /// long. The new elements are linked in all at once, so the list is left unchanged if `f`
+    /// panics.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
         }
+
+        let mut extra = self.new_sibling();
+        extra.extend(std::iter::repeat_with(f).take(new_len - self.len));
+        self.splice_back(&mut extra);
     }
 
     pub fn push_front(&mut self, item: T) {
@@ -57,6 +274,7 @@ impl<T> LinkedList<T> {
         unsafe {
             dummy.insert_after(item, self);
         }
+        self.notify(Event::Insert(0));
     }
 
     pub fn push_back(&mut self, item: T) {
@@ -64,16 +282,133 @@ impl<T> LinkedList<T> {
         unsafe {
             dummy.insert_before(item, self);
         }
+        self.notify(Event::Insert(self.len - 1));
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
         let dummy = self.dummy?;
-        unsafe { dummy.next().pop(self) }
+        let item = unsafe { dummy.next().pop(self) }?;
+        self.notify(Event::Remove(0, 1));
+        Some(item)
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
         let dummy = self.dummy?;
-        unsafe { dummy.prev().pop(self) }
+        let item = unsafe { dummy.prev().pop(self) }?;
+        self.notify(Event::Remove(self.len, 1));
+        Some(item)
+    }
+
+    /// Removes and returns the front element if `pred` returns true for it.
+    pub fn pop_front_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if pred(self.front_mut()?) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the back element if `pred` returns true for it.
+    pub fn pop_back_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if pred(self.back_mut()?) {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Inserts `item` at `index`, walking from whichever end of the list is closer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(
+            index <= self.len,
+            "insertion index {} out of bounds for a list of length {}",
+            index,
+            self.len
+        );
+
+        self.init();
+        let at = self.walk_to(index, None).unwrap();
+        unsafe { at.insert_before(item, self) };
+        self.notify(Event::Insert(index));
+    }
+
+    /// Removes the element at `index`, walking from whichever end of the list is closer.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let node = self.walk_to(index, None)?;
+        let item = unsafe { node.pop_unchecked(self) };
+        self.notify(Event::Remove(index, 1));
+        Some(item)
+    }
+
+    /// Removes the element at `index` and moves the front element into its place, like
+    /// `VecDeque::swap_remove_front`. Only the walk to `index` costs anything: the nodes are
+    /// relinked in constant time, whichever end `index` is nearer.
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let node = self.walk_to(index, None)?;
+        let front = self.front_node()?;
+        if node != front {
+            unsafe {
+                NodePtr::unlink(front, front, 1, self);
+                node.splice_before(front, front, 1, self);
+            }
+        }
+
+        let item = unsafe { node.pop_unchecked(self) };
+        self.notify(Event::Remove(index, 1));
+        // the front element only changes position relative to the others if it jumped any
+        if index > 1 {
+            self.notify(Event::Reorder);
+        }
+        Some(item)
+    }
+
+    /// Removes the element at `index` and moves the back element into its place, like
+    /// `VecDeque::swap_remove_back`.
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let node = self.walk_to(index, None)?;
+        let back = self.back_node()?;
+        let reordered = index + 2 < self.len;
+        if node != back {
+            unsafe {
+                NodePtr::unlink(back, back, 1, self);
+                node.splice_after(back, back, 1, self);
+            }
+        }
+
+        let item = unsafe { node.pop_unchecked(self) };
+        self.notify(Event::Remove(index, 1));
+        if reordered {
+            self.notify(Event::Reorder);
+        }
+        Some(item)
+    }
+
+    /// Returns the element at `index` in O(n), walking from whichever end is closer.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let node = (index < self.len).then(|| self.walk_to(index, None))??;
+        Some(unsafe { node.get_unchecked() })
+    }
+
+    /// Returns the element at `index` in O(n), walking from whichever end is closer.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let node = (index < self.len).then(|| self.walk_to(index, None))??;
+        Some(unsafe { node.get_mut_unchecked() })
     }
 
     pub fn front(&self) -> Option<&T> {
@@ -92,6 +427,39 @@ impl<T> LinkedList<T> {
         unsafe { self.dummy?.prev().get_mut(self) }
     }
 
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| indices[i]);
+
+        let mut sorted = order.iter().map(|&i| indices[i]);
+        if sorted
+            .clone()
+            .zip(sorted.clone().skip(1))
+            .any(|(a, b)| a == b)
+            || sorted.next_back().is_some_and(|max| max >= self.len)
+        {
+            return None;
+        }
+
+        let mut nodes = [None; N];
+        let mut targets = order.iter().peekable();
+        if let Some(iter) = unsafe { self.raw_iter() } {
+            for (index, node) in iter.enumerate() {
+                match targets.peek() {
+                    Some(&&slot) if indices[slot] == index => {
+                        nodes[slot] = Some(node);
+                        targets.next();
+                    }
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+        }
+
+        // Safety: the indices are distinct, so every node is handed out at most once
+        Some(nodes.map(|node| unsafe { node.unwrap().get_mut_unchecked() }))
+    }
+
     pub fn iter(&self) -> Iter<'_, T> {
         self.into_iter()
     }
@@ -100,46 +468,257 @@ impl<T> LinkedList<T> {
         self.into_iter()
     }
 
+    /// Removes the elements in `range` and returns them as an iterator. The range is unlinked
+    /// from the list straight away, so the list is left without them however much of the
+    /// iterator is consumed, even if it's leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = resolve_range(range, self.len);
+        let (_, drained) = self.detach_range(range.clone());
+        self.notify(Event::Remove(range.start, range.len()));
+        Drain::new(drained)
+    }
+
+    /// Returns an iterator that removes and yields the elements for which `pred` returns true,
+    /// from either end. `pred` sees every element it visits mutably, whether or not it's removed.
+    /// Elements the iterator hasn't reached when it's dropped stay in the list.
     pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> DrainFilter<'_, T, F> {
-        DrainFilter::new(self, pred)
-    }
-
-    // pub fn cursor(&self) -> Cursor<'_, T> {
-    //     Cursor {
-    //         node: self.dummy,
-    //         list: self,
-    //         index: self.len(),
-    //     }
-    // }
-
-    // pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
-    //     CursorMut {
-    //         index: self.len(),
-    //         node: self.init(),
-    //         list: self,
-    //     }
-    // }
+        let len = self.len;
+        DrainFilter::new(self, 0..len, pred)
+    }
+
+    /// Like [`drain_filter`](LinkedList::drain_filter), but only visits the elements in
+    /// `range`, matching the signature of `Vec::extract_if`. Elements outside the range are
+    /// never passed to `pred` and always stay in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn extract_if<R: RangeBounds<usize>, F: FnMut(&mut T) -> bool>(
+        &mut self,
+        range: R,
+        pred: F,
+    ) -> DrainFilter<'_, T, F> {
+        let range = resolve_range(range, self.len);
+        DrainFilter::new(self, range, pred)
+    }
+
+    /// Returns a cursor at the front element, or at the ghost element if the list is empty.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::from_raw(RawCursor::front(self), self)
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut::from_raw(RawCursor::front(self), self)
+    }
+
+    /// Returns a cursor at the back element, or at the ghost element if the list is empty.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor::from_raw(RawCursor::back(self), self)
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut::from_raw(RawCursor::back(self), self)
+    }
+
+    // `index` must be at most len, with len giving the ghost element
+    pub(crate) fn raw_cursor_at(&self, index: usize) -> RawCursor<T> {
+        match self.walk_to(index, None) {
+            Some(node) => RawCursor::from_node(node, index),
+            None => RawCursor::new(self),
+        }
+    }
+
+    /// Returns a cursor at `index`, walking from whichever end of the list is closer. Position
+    /// `len` is the ghost element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn cursor_at(&self, index: usize) -> Cursor<'_, T> {
+        self.check_cursor_index(index);
+        Cursor::from_raw(self.raw_cursor_at(index), self)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn cursor_at_mut(&mut self, index: usize) -> CursorMut<'_, T> {
+        self.check_cursor_index(index);
+        CursorMut::from_raw(self.raw_cursor_at(index), self)
+    }
+
+    fn check_cursor_index(&self, index: usize) {
+        assert!(
+            index <= self.len,
+            "cannot place a cursor at position {} in a list of length {}",
+            index,
+            self.len
+        );
+    }
+}
+
+// resolves `range` against a list of length `len`, panicking like slice indexing does
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index list from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index list up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "list index starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end index {} out of range for list of length {}",
+        end,
+        len
+    );
+    start..end
+}
+
+// Frees a chain of nodes that is no longer reachable from its list. If dropping one of the
+// elements panics, the guard frees the rest of the chain while unwinding.
+struct DeallocChain<'a, T> {
+    iter: RawIter<T>,
+    pool: Option<&'a RawPool>,
+}
+
+impl<'a, T> DeallocChain<'a, T> {
+    fn dealloc_all(&mut self) {
+        for node in &mut self.iter {
+            unsafe {
+                node.dealloc_unchecked(self.pool);
+            }
+        }
+    }
+}
+
+impl<'a, T> Drop for DeallocChain<'a, T> {
+    fn drop(&mut self) {
+        self.dealloc_all();
+    }
 }
 
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
-        self.clear();
+        // the dummy goes first, so that it isn't leaked if an element panics on drop
+        let iter = unsafe { self.raw_iter() };
         unsafe {
-            self.dummy.map(|ptr| ptr.dealloc_raw());
+            self.dummy.take().map(|ptr| ptr.dealloc_raw_in(self.pool()));
+        }
+
+        if let Some(iter) = iter {
+            DeallocChain {
+                iter,
+                pool: self.pool(),
+            }
+            .dealloc_all();
         }
     }
 }
 
 impl<T: Clone> Clone for LinkedList<T> {
+    // the clone allocates from the same pool as the original, if any
     fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        let mut list = self.new_sibling();
+        list.extend(self.iter().cloned());
+        list
+    }
+
+    // overwrites the existing elements in place, so nodes are only allocated or freed for the
+    // difference in length
+    fn clone_from(&mut self, source: &Self) {
+        for (item, source) in self.iter_mut().zip(source) {
+            item.clone_from(source);
+        }
+
+        if self.len > source.len {
+            let surplus = self.len - source.len;
+            let front = unsafe { self.raw_iter() }.and_then(|mut iter| iter.nth(source.len));
+            if let Some(front) = front {
+                drop(unsafe { self.detach_back(front, surplus) });
+            }
+        } else {
+            let mut rest = self.new_sibling();
+            rest.extend(source.iter().skip(self.len).cloned());
+            self.splice_back(&mut rest);
+        }
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        let mut cloned = self.new_sibling();
+        cloned.extend(slice.iter().cloned());
+        self.splice_back(&mut cloned);
+    }
+
+    /// Clones the elements in `src` and appends them to the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R) {
+        let Range { start, end } = resolve_range(src, self.len);
+        let mut cloned = self.new_sibling();
+        cloned.extend(self.iter().skip(start).take(end - start).cloned());
+        self.splice_back(&mut cloned);
+    }
+}
+
+// Reports the elements appended by `extend` to observers as a single splice once it's done,
+// including when the iterator panics part way through.
+struct ExtendGuard<'a, T> {
+    list: &'a mut LinkedList<T>,
+    index: usize,
+}
+
+impl<'a, T> Drop for ExtendGuard<'a, T> {
+    fn drop(&mut self) {
+        let len = self.list.len - self.index;
+        self.list.notify(Event::Splice(self.index, len));
     }
 }
 
 impl<T> Extend<T> for LinkedList<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let index = self.len;
+        let guard = ExtendGuard { list: self, index };
         for item in iter {
-            self.push_back(item);
+            let dummy = guard.list.init();
+            unsafe { dummy.insert_before(item, guard.list) };
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+// each list is spliced on in constant time
+impl<T> Extend<LinkedList<T>> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = LinkedList<T>>>(&mut self, iter: I) {
+        for mut list in iter {
+            self.append(&mut list);
         }
     }
 }
@@ -152,9 +731,28 @@ impl<T> FromIterator<T> for LinkedList<T> {
     }
 }
 
+// Indexing walks the list, so it's O(n), like `get`.
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {} out of bounds for a list of length {}", index, len))
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {} out of bounds for a list of length {}", index, len))
+    }
+}
+
 impl<T: Debug> Debug for LinkedList<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(self).finish()
+        self.fmt_debug(f)
     }
 }
 
@@ -193,12 +791,44 @@ impl<T: Hash> Hash for LinkedList<T> {
 }
 
 unsafe impl<T: Send> Send for LinkedList<T> {}
+// the observer isn't `Sync`, but it's only reachable through `&mut self`
 unsafe impl<T: Sync> Sync for LinkedList<T> {}
 
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// use too_many_linked_list::sixth::LinkedList;
+///
+/// fn is_send<T: Send>(_: T) {}
+///
+/// is_send(LinkedList::<Rc<i32>>::new());
+/// ```
+#[allow(unused)]
+fn linked_list_send_requires_send() {}
+
+/// ```compile_fail
+/// use too_many_linked_list::sixth::LinkedList;
+///
+/// let list: LinkedList<i32> = (0..3).collect();
+/// let (a, b) = (&list, &list);
+/// let front = a.front_mut().unwrap();
+/// *front += *b.front().unwrap();
+/// ```
+#[allow(unused)]
+fn front_mut_requires_unique_access() {}
+
 #[cfg(test)]
 mod test {
+    use std::{
+        cell::Cell,
+        panic::{self, AssertUnwindSafe},
+        rc::Rc,
+    };
 
-    use super::LinkedList;
+    use super::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
 
     fn generate_test() -> LinkedList<i32> {
         list_from(&[0, 1, 2, 3, 4, 5, 6])
@@ -248,6 +878,115 @@ mod test {
         assert_eq!(list.len(), 0);
     }
 
+    #[test]
+    fn test_pop_if() {
+        let mut list = list_from(&[1, 2, 3, 4]);
+        assert_eq!(list.pop_front_if(|x| *x % 2 == 0), None);
+        assert_eq!(list.pop_front_if(|x| *x % 2 == 1), Some(1));
+        assert_eq!(list.pop_back_if(|x| *x > 10), None);
+        assert_eq!(
+            list.pop_back_if(|x| {
+                *x *= 10;
+                true
+            }),
+            Some(40)
+        );
+        assert_eq!(
+            list.pop_front_if(|x| {
+                *x = -2;
+                false
+            }),
+            None
+        );
+        assert_eq!(list, [-2, 3]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(
+            empty.pop_front_if(|_| panic!("called on an empty list")),
+            None
+        );
+        assert_eq!(empty.pop_back_if(|_| true), None);
+    }
+
+    #[test]
+    fn test_cursor_at() {
+        let mut list = list_from(&[0, 1, 2, 3, 4, 5]);
+        let cursor = list.cursor_at(4);
+        assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&4)));
+        assert_eq!(list.cursor_at(1).peek_prev(), Some(&0));
+        assert_eq!(list.cursor_at(6).index(), None);
+
+        let mut cursor = list.cursor_at_mut(2);
+        cursor.insert_before(10);
+        assert_eq!(list, [0, 1, 10, 2, 3, 4, 5]);
+        assert_eq!(LinkedList::<i32>::new().cursor_at(0).current(), None);
+    }
+
+    #[test]
+    #[should_panic = "cannot place a cursor at position 4 in a list of length 3"]
+    fn test_cursor_at_out_of_bounds() {
+        list_from(&[0, 1, 2]).cursor_at(4);
+    }
+
+    #[test]
+    fn test_extend_front() {
+        let mut list = list_from(&[3, 4]);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.extend_front(0..3);
+        assert_eq!(list, [0, 1, 2, 3, 4]);
+        list.extend_front(vec![-2, -1]);
+        assert_eq!(list, [-2, -1, 0, 1, 2, 3, 4]);
+        assert_eq!(events.take(), [Event::Splice(0, 3), Event::Splice(0, 2)]);
+
+        let mut empty = LinkedList::new();
+        empty.extend_front([1, 2]);
+        assert_eq!(empty, [1, 2]);
+        list.validate();
+        empty.validate();
+    }
+
+    #[test]
+    fn test_swap_remove() {
+        let mut list = list_from(&[0, 1, 2, 3, 4, 5]);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        assert_eq!(list.swap_remove_front(3), Some(3));
+        assert_eq!(list, [1, 2, 0, 4, 5]);
+        assert_eq!(list.swap_remove_back(1), Some(2));
+        assert_eq!(list, [1, 5, 0, 4]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(3, 1),
+                Event::Reorder,
+                Event::Remove(1, 1),
+                Event::Reorder
+            ]
+        );
+
+        // next to the end being swapped in from, nothing else moves
+        assert_eq!(list.swap_remove_front(1), Some(5));
+        assert_eq!(list.swap_remove_back(1), Some(0));
+        assert_eq!(list.swap_remove_front(0), Some(1));
+        assert_eq!(list, [4]);
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(1, 1),
+                Event::Remove(1, 1),
+                Event::Remove(0, 1)
+            ]
+        );
+
+        assert_eq!(list.swap_remove_back(1), None);
+        assert_eq!(list.swap_remove_back(0), Some(4));
+        assert_eq!(list.swap_remove_front(0), None);
+        list.validate();
+    }
+
     #[test]
     fn test_basic() {
         let mut m = LinkedList::new();
@@ -288,6 +1027,298 @@ mod test {
         assert_eq!(n.pop_front(), Some(1));
     }
 
+    #[test]
+    fn test_insert_remove() {
+        let mut list = generate_test();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.insert(0, -1);
+        list.insert(8, 7);
+        list.insert(3, 100);
+        assert!(list.iter().eq(&[-1, 0, 1, 100, 2, 3, 4, 5, 6, 7]));
+        assert!(list.iter().rev().eq(&[7, 6, 5, 4, 3, 2, 100, 1, 0, -1]));
+
+        assert_eq!(list.remove(3), Some(100));
+        assert_eq!(list.remove(8), Some(7));
+        assert_eq!(list.remove(0), Some(-1));
+        assert_eq!(list.remove(7), None);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4, 5, 6]));
+        assert_eq!(
+            events.take(),
+            [
+                Event::Insert(0),
+                Event::Insert(8),
+                Event::Insert(3),
+                Event::Remove(3, 1),
+                Event::Remove(8, 1),
+                Event::Remove(0, 1)
+            ]
+        );
+
+        let mut empty = LinkedList::new();
+        assert_eq!(empty.remove(0), None);
+        empty.insert(0, 'a');
+        assert_eq!(empty.remove(0), Some('a'));
+        empty.validate();
+    }
+
+    #[test]
+    #[should_panic = "insertion index 8 out of bounds for a list of length 7"]
+    fn test_insert_out_of_bounds() {
+        generate_test().insert(8, 0);
+    }
+
+    #[test]
+    fn test_truncate_resize_with() {
+        let mut list = generate_test();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.truncate(10);
+        list.truncate(7);
+        list.truncate(4);
+        assert!(list.iter().eq(&[0, 1, 2, 3]));
+        assert_eq!(events.take(), [Event::Remove(4, 3)]);
+
+        let mut next = 10;
+        list.resize_with(6, || {
+            next += 1;
+            next
+        });
+        assert!(list.iter().eq(&[0, 1, 2, 3, 11, 12]));
+        assert_eq!(events.take(), [Event::Splice(4, 2)]);
+
+        list.resize_with(2, || unreachable!());
+        assert!(list.iter().rev().eq(&[1, 0]));
+        list.truncate(0);
+        assert!(list.is_empty());
+        list.validate();
+        LinkedList::<i32>::new().truncate(0);
+    }
+
+    #[test]
+    fn test_resize_with_panic() {
+        let mut list = generate_test();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut count = 0;
+            list.resize_with(10, || {
+                count += 1;
+                assert!(count < 3, "generator failed");
+                count
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 7);
+        list.validate();
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = generate_test();
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(5), Some(&5));
+        assert_eq!(list.get(7), None);
+        *list.get_mut(6).unwrap() = 60;
+        assert_eq!(list.get_mut(100), None);
+
+        list[1] += 10;
+        assert_eq!((list[1], list[2], list[6]), (11, 2, 60));
+        assert_eq!(LinkedList::<i32>::new().get(0), None);
+    }
+
+    #[test]
+    #[should_panic = "index 7 out of bounds for a list of length 7"]
+    fn test_index_out_of_bounds() {
+        let list = generate_test();
+        let _ = list[7];
+    }
+
+    #[test]
+    fn test_get_many_mut() {
+        let mut list = generate_test();
+
+        let [a, b, c] = list.get_many_mut([5, 0, 3]).unwrap();
+        assert_eq!((*a, *b, *c), (5, 0, 3));
+        std::mem::swap(a, b);
+        *c *= 10;
+        assert!(list.iter().eq(&[5, 1, 2, 30, 4, 0, 6]));
+
+        assert!(list.get_many_mut([1, 1]).is_none());
+        assert!(list.get_many_mut([0, 7]).is_none());
+        assert!(list.get_many_mut([]).is_some());
+        assert!(LinkedList::<i32>::new().get_many_mut([]).is_some());
+        assert!(LinkedList::<i32>::new().get_many_mut([0]).is_none());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list = list_from(&[0, 1, 2]);
+        let mut other = list_from(&[3, 4]);
+        let front = other.front().map(|x| x as *const i32);
+
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 5);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4]));
+        assert!(list.iter().rev().eq(&[4, 3, 2, 1, 0]));
+        assert_eq!(list.iter().nth(3).map(|x| x as *const i32), front);
+
+        other.append(&mut list);
+        other.append(&mut LinkedList::new());
+        assert!(list.is_empty());
+        assert!(other.iter().eq(&[0, 1, 2, 3, 4]));
+
+        let mut empty = LinkedList::new();
+        empty.append(&mut other);
+        empty.validate();
+        assert_eq!(empty.len(), 5);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = list_from(&[3, 4]);
+        let mut other = list_from(&[0, 1, 2]);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.prepend(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 5);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4]));
+        assert!(list.iter().rev().eq(&[4, 3, 2, 1, 0]));
+        assert_eq!(events.take(), [Event::Splice(0, 3)]);
+
+        list.prepend(&mut LinkedList::new());
+        assert_eq!(events.take(), []);
+
+        other.prepend(&mut list);
+        other.validate();
+        assert!(list.is_empty());
+        assert!(other.iter().eq(&[0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_extend_refs() {
+        fn fill<C: Extend<i32> + for<'a> Extend<&'a i32>>(c: &mut C, items: &[i32]) {
+            c.extend(items);
+            c.extend(items.iter().map(|x| x * 10));
+        }
+
+        let mut list = list_from(&[0]);
+        fill(&mut list, &[1, 2]);
+        list.extend(&list_from(&[3]));
+        assert_eq!(list, [0, 1, 2, 10, 20, 3]);
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn test_concat() {
+        let lists = [
+            list_from(&[0, 1]),
+            list_from(&[]),
+            list_from(&[2]),
+            list_from(&[3, 4]),
+        ];
+        let fronts: Vec<_> = lists
+            .iter()
+            .filter_map(|l| l.front().map(|x| x as *const i32))
+            .collect();
+
+        let list = LinkedList::concat(lists);
+        assert_eq!(list, [0, 1, 2, 3, 4]);
+        assert!(list.iter().rev().eq(&[4, 3, 2, 1, 0]));
+        // spliced, not copied
+        assert_eq!(list.iter().nth(2).unwrap() as *const i32, fronts[1]);
+        list.validate();
+
+        let mut list = list;
+        list.extend(vec![list_from(&[5]), list_from(&[6, 7])]);
+        assert_eq!(list, [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert!(LinkedList::<i32>::concat([]).is_empty());
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut list = list_from(&[1, 2]);
+        list.extend_from_slice(&[3, 4, 5]);
+        list.extend_from_slice(&[]);
+        assert!(list.iter().eq(&[1, 2, 3, 4, 5]));
+        assert_eq!(list.len(), 5);
+
+        let mut list = LinkedList::new();
+        list.extend_from_slice(&[String::from("a"), String::from("b")]);
+        assert!(list.iter().rev().eq(&["b", "a"]));
+    }
+
+    #[test]
+    fn test_extend_from_within() {
+        let mut list = list_from(&[0, 1, 2, 3]);
+        list.extend_from_within(1..3);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 1, 2]));
+
+        list.extend_from_within(..=0);
+        list.extend_from_within(6..);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 1, 2, 0, 0]));
+        assert_eq!(list.len(), 8);
+
+        let mut empty = LinkedList::<i32>::new();
+        empty.extend_from_within(..);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extend_from_within_out_of_bounds() {
+        let mut list = list_from(&[0, 1, 2]);
+        list.extend_from_within(2..4);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let source = list_from(&[String::from("a"), String::from("b"), String::from("c")]);
+
+        let mut longer = list_from(&vec![String::from("x"); 5]);
+        let front = longer.front().unwrap() as *const String;
+        longer.clone_from(&source);
+        assert_eq!(longer, source);
+        assert_eq!(longer.len(), 3);
+        assert_eq!(longer.front().unwrap() as *const String, front);
+        assert!(longer.iter().rev().eq(source.iter().rev()));
+
+        let mut shorter = list_from(&[String::from("y")]);
+        shorter.clone_from(&source);
+        assert_eq!(shorter, source);
+        assert!(shorter.iter().rev().eq(source.iter().rev()));
+
+        let mut empty = LinkedList::new();
+        empty.clone_from(&source);
+        assert_eq!(empty, source);
+        empty.clone_from(&LinkedList::new());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_clone_from_reuses_nodes() {
+        let addrs = |list: &LinkedList<Vec<u8>>| {
+            list.iter().map(|x| x as *const Vec<u8>).collect::<Vec<_>>()
+        };
+
+        let mut list: LinkedList<Vec<u8>> = (0..4).map(|i| vec![i; 8]).collect();
+        let before = addrs(&list);
+
+        list.clone_from(&(10..16).map(|i| vec![i; 2]).collect());
+        assert!(list
+            .iter()
+            .eq(&(10..16).map(|i| vec![i; 2]).collect::<Vec<_>>()));
+        assert_eq!(addrs(&list)[..4], before[..]);
+
+        list.clone_from(&(20..22).map(|i| vec![i]).collect());
+        assert!(list.iter().eq(&[vec![20], vec![21]]));
+        assert_eq!(addrs(&list)[..], before[..2]);
+        list.validate();
+    }
+
     #[test]
     fn test_iterator() {
         let m = generate_test();
@@ -322,6 +1353,7 @@ mod test {
         assert_eq!(it.next(), None);
     }
 
+    #[allow(clippy::manual_next_back)]
     #[test]
     fn test_rev_iter() {
         let m = generate_test();
@@ -435,18 +1467,33 @@ mod test {
         assert!(s >= one);
     }
 
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn test_trusted_len() {
+        fn trusted_len<I: std::iter::TrustedLen>(iter: I) -> I {
+            iter
+        }
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let refs: Vec<&mut i32> = trusted_len(list.iter_mut()).skip(1).collect();
+        assert_eq!(refs.len(), 4);
+        let doubled: Vec<i32> = trusted_len(list.iter()).map(|x| x * 2).collect();
+        assert_eq!(doubled, [0, 2, 4, 6, 8]);
+        let items: Vec<i32> = trusted_len(list.into_iter()).rev().collect();
+        assert_eq!(items, [4, 3, 2, 1, 0]);
+    }
+
     #[test]
     fn test_debug() {
         let list: LinkedList<i32> = (0..10).collect();
         assert_eq!(format!("{:?}", list), "[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]");
 
-        let list: LinkedList<&str> = vec!["just", "one", "test", "more"]
-            .iter()
-            .copied()
-            .collect();
+        let list: LinkedList<&str> = ["just", "one", "test", "more"].iter().copied().collect();
         assert_eq!(format!("{:?}", list), r#"["just", "one", "test", "more"]"#);
     }
 
+    // the pool behind a list is behind a mutex, but `Hash` and `Eq` never look at it
+    #[allow(clippy::mutable_key_type)]
     #[test]
     fn test_hashmap() {
         // Check that HashMap works with this as a key
@@ -469,6 +1516,33 @@ mod test {
         assert!(map.is_empty());
     }
 
+    #[test]
+    fn test_hash_matches_std() {
+        use std::{
+            collections::{hash_map::DefaultHasher, LinkedList as StdList},
+            hash::{Hash, Hasher},
+        };
+
+        fn hash_of(x: impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            x.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for len in 0..5 {
+            let list: LinkedList<String> = (0..len).map(|i| i.to_string()).collect();
+            let std_list: StdList<String> = (0..len).map(|i| i.to_string()).collect();
+            assert_eq!(hash_of(&list), hash_of(&std_list));
+        }
+
+        // the length prefix keeps differently split nested lists apart
+        let a: LinkedList<LinkedList<i32>> =
+            [list_from(&[1]), list_from(&[2, 3])].into_iter().collect();
+        let b: LinkedList<LinkedList<i32>> =
+            [list_from(&[1, 2]), list_from(&[3])].into_iter().collect();
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
     #[test]
     fn test_drain() {
         let mut list = (0..10).collect::<LinkedList<_>>();
@@ -492,6 +1566,86 @@ mod test {
         assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 6, 7, 8]);
     }
 
+    #[test]
+    fn test_drain_partial() {
+        let mut list = (0..10).collect::<LinkedList<_>>();
+
+        let mut drain = list.drain_filter(|i| *i % 3 == 0);
+        assert_eq!(drain.next(), Some(0));
+        assert_eq!(drain.next_back(), Some(9));
+        assert_eq!(drain.next(), Some(3));
+
+        assert_eq!(list.len(), 7);
+        assert!(list.iter().eq(&[1, 2, 4, 5, 6, 7, 8]));
+        assert!(list.drain_filter(|_| false).next().is_none());
+        assert_eq!(list.drain_filter(|_| true).count(), 7);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_drain_range() {
+        let mut list = (0..10).collect::<LinkedList<_>>();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut drain = list.drain(2..7);
+        assert_eq!(drain.len(), 5);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(6));
+        assert_eq!(drain.len(), 3);
+        assert!(drain.eq([3, 4, 5]));
+        assert!(list.iter().eq(&[0, 1, 7, 8, 9]));
+        assert_eq!(events.take(), [Event::Remove(2, 5)]);
+
+        // dropping the iterator early still removes the whole range
+        let mut drain = list.drain(3..);
+        assert_eq!(drain.next(), Some(8));
+        assert!(list.iter().eq(&[0, 1, 7]));
+
+        assert_eq!(list.drain(1..1).count(), 0);
+        assert!(list.drain(..).eq([0, 1, 7]));
+        assert!(list.is_empty());
+        list.validate();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_drain_range_out_of_bounds() {
+        generate_test().drain(5..8);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = (0..10).collect::<LinkedList<_>>();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let mut seen = Vec::new();
+        let mut extract = list.extract_if(2..8, |x| {
+            seen.push(*x);
+            *x % 2 == 0
+        });
+        assert_eq!(extract.next(), Some(2));
+        assert_eq!(extract.next_back(), Some(6));
+        assert_eq!(extract.next(), Some(4));
+        assert_eq!(extract.next(), None);
+        assert_eq!(seen, [2, 7, 6, 3, 4, 5]);
+        assert!(list.iter().eq(&[0, 1, 3, 5, 7, 8, 9]));
+        assert_eq!(
+            events.take(),
+            [
+                Event::Remove(2, 1),
+                Event::Remove(5, 1),
+                Event::Remove(3, 1)
+            ]
+        );
+
+        assert!(list.extract_if(5.., |_| true).eq([8, 9]));
+        assert_eq!(list.extract_if(1..1, |_| true).count(), 0);
+        assert!(list.extract_if(.., |x| *x > 3).eq([5, 7]));
+        assert!(list.iter().eq(&[0, 1, 3]));
+    }
+
     #[test]
     fn test_drain_panic() {
         fn panic() {
@@ -506,9 +1660,195 @@ mod test {
         assert!(std::panic::catch_unwind(panic).is_err());
     }
 
+    // clones fine until `fuse` runs out, and counts how many of its kind are dropped
+    #[derive(Debug)]
+    struct Fragile {
+        fuse: Rc<Cell<usize>>,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Fragile {
+        fn list(len: usize, fuse: &Rc<Cell<usize>>, drops: &Rc<Cell<usize>>) -> LinkedList<Self> {
+            (0..len)
+                .map(|_| Fragile {
+                    fuse: fuse.clone(),
+                    drops: drops.clone(),
+                })
+                .collect()
+        }
+    }
+
+    impl Clone for Fragile {
+        fn clone(&self) -> Self {
+            let fuse = self.fuse.get();
+            assert!(fuse != 0, "clone failed");
+            self.fuse.set(fuse - 1);
+            Fragile {
+                fuse: self.fuse.clone(),
+                drops: self.drops.clone(),
+            }
+        }
+    }
+
+    impl Drop for Fragile {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_clone_panic() {
+        let (fuse, drops) = (Rc::new(Cell::new(2)), Rc::new(Cell::new(0)));
+        let list = Fragile::list(5, &fuse, &drops);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.clone()));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 2);
+
+        list.validate();
+        assert_eq!(list.len(), 5);
+        drop(list);
+        assert_eq!(drops.get(), 7);
+    }
+
+    #[test]
+    fn test_clone_from_panic() {
+        let (fuse, drops) = (Rc::new(Cell::new(4)), Rc::new(Cell::new(0)));
+        let source = Fragile::list(6, &fuse, &drops);
+        let mut list = Fragile::list(2, &fuse, &drops);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        // the two existing elements are overwritten, and the clones for the new ones are
+        // discarded without touching the list
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.clone_from(&source)));
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(list.len(), 2);
+        assert_eq!(events.take(), []);
+        assert_eq!(drops.get(), 4);
+
+        drop((list, source));
+        assert_eq!(drops.get(), 12);
+    }
+
+    #[test]
+    fn test_extend_from_slice_panic() {
+        let (fuse, drops) = (Rc::new(Cell::new(3)), Rc::new(Cell::new(0)));
+        let slice: Vec<_> = Fragile::list(5, &fuse, &drops).into_iter().collect();
+        let mut list = Fragile::list(1, &fuse, &drops);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.extend_from_slice(&slice)));
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(list.len(), 1);
+        assert_eq!(events.take(), []);
+        assert_eq!(drops.get(), 3);
+
+        fuse.set(1);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.extend_from_within(..)));
+        assert!(result.is_ok());
+        fuse.set(1);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.extend_from_within(..)));
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(list.len(), 2);
+        assert_eq!(events.take(), [Event::Splice(1, 1)]);
+        assert_eq!(drops.get(), 4);
+
+        drop((list, slice));
+        assert_eq!(drops.get(), 11);
+    }
+
+    #[test]
+    fn test_extend_panic() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.extend((3..10).map(|x| if x < 6 { x } else { panic!("source failed") }))
+        }));
+        assert!(result.is_err());
+        list.validate();
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4, 5]));
+
+        let result = panic::catch_unwind(|| {
+            (0..10)
+                .map(|x| if x < 6 { x } else { panic!("source failed") })
+                .collect::<LinkedList<i32>>()
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_drop_panic() {
+        let drops = Rc::new(Cell::new(0));
+
+        struct Bomb(bool, Rc<Cell<usize>>);
+
+        impl Drop for Bomb {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+                assert!(!self.0, "drop failed");
+            }
+        }
+
+        let bombs = |drops: &Rc<Cell<usize>>| {
+            (0..5)
+                .map(|i| Bomb(i == 1, drops.clone()))
+                .collect::<LinkedList<_>>()
+        };
+
+        let mut list = bombs(&drops);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.clear()));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 5);
+        assert_eq!(events.take(), [Event::Clear(5)]);
+        list.validate();
+        assert!(list.is_empty());
+        list.push_back(Bomb(false, drops.clone()));
+        assert_eq!(list.len(), 1);
+        drop(list);
+        assert_eq!(drops.get(), 6);
+
+        let list = bombs(&drops);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| drop(list)));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 11);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        let mut list: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        list = std::thread::spawn(move || {
+            list.push_back(String::from("c"));
+            list
+        })
+        .join()
+        .unwrap();
+
+        std::thread::scope(|s| {
+            let list = &list;
+            let handles = [
+                s.spawn(move || list.iter().map(String::len).sum::<usize>()),
+                s.spawn(move || list.cursor_back().current().map_or(0, String::len)),
+            ];
+            let lens: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+            assert_eq!(lens, [3, 1]);
+        });
+
+        let mut cursor = list.cursor_front_mut();
+        std::thread::scope(|s| {
+            s.spawn(|| cursor.current().unwrap().push('!'));
+        });
+        assert_eq!(list, ["a!", "b", "c"]);
+    }
+
     #[allow(dead_code)]
     fn assert_properties() {
-        use super::iter::*;
+        use super::{iter::*, Cursor, CursorMut};
 
         fn is_send<T: Send>() {}
         fn is_sync<T: Sync>() {}
@@ -525,8 +1865,14 @@ mod test {
         is_send::<IterMut<i32>>();
         is_sync::<IterMut<i32>>();
 
-        // is_send::<Cursor<i32>>();
-        // is_sync::<Cursor<i32>>();
+        is_send::<Cursor<i32>>();
+        is_sync::<Cursor<i32>>();
+
+        is_send::<CursorMut<i32>>();
+        is_sync::<CursorMut<i32>>();
+
+        // a shared iterator hands out `&T`, so sending one needs `T: Sync`, not `T: Send`
+        is_send::<Iter<std::sync::Mutex<i32>>>();
 
         fn linked_list_covariant<'a, T>(x: LinkedList<&'static T>) -> LinkedList<&'a T> {
             x
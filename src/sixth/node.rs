@@ -1,6 +1,7 @@
 use std::{
+    alloc::Layout,
     fmt::Debug,
-    mem::MaybeUninit,
+    mem::{self, MaybeUninit},
     ops::Not,
     ptr::{self, NonNull},
 };
@@ -8,9 +9,12 @@ use std::{
 #[cfg(feature = "debug-alloc")]
 use std::backtrace::Backtrace;
 
-use super::{iter::RawIter, LinkedList};
+use super::{iter::RawIter, pool::RawPool, LinkedList};
 
+// repr(C) so that nodes of different element types with the same layout agree on where every
+// field lives, which lets a node allocation be reused for another element type
 #[derive(Debug)]
+#[repr(C)]
 pub struct Node<T> {
     pub(crate) prev: NodePtr<T>,
     pub(crate) next: NodePtr<T>,
@@ -21,6 +25,12 @@ impl<T> Node<T> {
     pub(crate) fn new(prev: NodePtr<T>, item: MaybeUninit<T>, next: NodePtr<T>) -> Self {
         Self { prev, next, item }
     }
+
+    // whether an allocation made for a Node<T> can hold a Node<U> and be freed as one
+    pub(crate) fn same_layout<U>() -> bool {
+        Layout::new::<Node<T>>() == Layout::new::<Node<U>>()
+            && mem::offset_of!(Node<T>, item) == mem::offset_of!(Node<U>, item)
+    }
 }
 
 pub struct NodePtr<T> {
@@ -35,7 +45,7 @@ impl<T> Debug for NodePtr<T> {
 
 impl<T> Clone for NodePtr<T> {
     fn clone(&self) -> Self {
-        Self { ptr: self.ptr }
+        *self
     }
 }
 
@@ -74,6 +84,33 @@ impl<T> NodePtr<T> {
         Self { ptr }
     }
 
+    // takes the allocation from `pool` if it has one to spare
+    pub unsafe fn raw_alloc_in(
+        prev: Self,
+        item: MaybeUninit<T>,
+        next: Self,
+        pool: Option<&RawPool>,
+    ) -> Self {
+        let Some(block) = pool.and_then(RawPool::take) else {
+            return Self::raw_alloc(prev, item, next);
+        };
+
+        let ptr = block.cast::<Node<T>>();
+        ptr.as_ptr().write(Node::new(prev, item, next));
+
+        #[cfg(feature = "debug-alloc")]
+        {
+            println!(
+                "Reused {} bytes at ptr {:p} from a pool: ",
+                std::mem::size_of::<Node<T>>(),
+                ptr.as_ptr()
+            );
+            println!("{}\n", Backtrace::capture());
+        }
+
+        Self { ptr }
+    }
+
     pub unsafe fn alloc_dangling(item: T) -> Self {
         let dangling = Self::dangling();
         Self::alloc(dangling, item, dangling)
@@ -83,11 +120,11 @@ impl<T> NodePtr<T> {
         unsafe { Self::raw_alloc(prev, MaybeUninit::new(item), next) }
     }
 
-    pub fn dummy() -> Self {
+    pub fn dummy(pool: Option<&RawPool>) -> Self {
         unsafe {
             let dangling = Self::dangling();
 
-            let dummy = Self::raw_alloc(dangling, MaybeUninit::uninit(), dangling);
+            let dummy = Self::raw_alloc_in(dangling, MaybeUninit::uninit(), dangling, pool);
             dummy.set_prev(dummy);
             dummy.set_next(dummy);
 
@@ -121,7 +158,14 @@ impl<T> NodePtr<T> {
     }
 
     pub fn is_dummy(self, list: &LinkedList<T>) -> bool {
-        list.dummy.map_or(false, |dummy| self == dummy)
+        list.dummy == Some(self)
+    }
+
+    // the node must not be part of any list, and Node::<T>::same_layout::<U>() must hold
+    pub unsafe fn cast<U>(self) -> NodePtr<U> {
+        NodePtr {
+            ptr: self.ptr.cast(),
+        }
     }
 
     pub fn as_ptr(self) -> *mut Node<T> {
@@ -164,13 +208,19 @@ impl<T> NodePtr<T> {
 
     // need to guarantee that self is a node in list
     pub unsafe fn insert_after(self, item: T, list: &mut LinkedList<T>) {
-        let new_node = Self::alloc_dangling(item);
+        let dangling = Self::dangling();
+        let new_node = Self::raw_alloc_in(dangling, MaybeUninit::new(item), dangling, list.pool());
+        #[cfg(feature = "debug-alloc")]
+        println!("Node {:p} belongs to {}\n", new_node.as_ptr(), list.label());
         self.splice_after(new_node, new_node, 1, list);
     }
 
     // need to guarantee that self is a node in list
     pub unsafe fn insert_before(self, item: T, list: &mut LinkedList<T>) {
-        let new_node = Self::alloc_dangling(item);
+        let dangling = Self::dangling();
+        let new_node = Self::raw_alloc_in(dangling, MaybeUninit::new(item), dangling, list.pool());
+        #[cfg(feature = "debug-alloc")]
+        println!("Node {:p} belongs to {}\n", new_node.as_ptr(), list.label());
         self.splice_before(new_node, new_node, 1, list);
     }
 
@@ -183,6 +233,13 @@ impl<T> NodePtr<T> {
         Self::slice_off(self, self, 1, list).next().unwrap()
     }
 
+    // unlink a part of the linked list without deallocating it
+    // the slice CANNOT include the dummy node
+    pub unsafe fn unlink(front: Self, back: Self, len: usize, list: &mut LinkedList<T>) {
+        front.prev().link(back.next());
+        list.len = list.len.saturating_sub(len);
+    }
+
     // slice off a part of the linked list
     // the slice CANNOT include the dummy node
     pub unsafe fn slice_off(
@@ -191,12 +248,12 @@ impl<T> NodePtr<T> {
         len: usize,
         list: &mut LinkedList<T>,
     ) -> impl Iterator<Item = T> {
-        front.prev().link(back.next());
-        list.len = list.len.saturating_sub(len);
+        Self::unlink(front, back, len, list);
 
+        let pool = list.pool.clone();
         let mut iter = RawIter::new(front, back, len);
-        iter.map(|node| {
-            let (_, item, _) = node.dealloc_unchecked();
+        iter.map(move |node| {
+            let (_, item, _) = node.dealloc_unchecked(pool.as_deref());
             item
         })
     }
@@ -209,10 +266,9 @@ impl<T> NodePtr<T> {
         len: usize,
         list: &mut LinkedList<T>,
     ) -> LinkedList<T> {
-        front.prev().link(back.next());
-        list.len = list.len.saturating_sub(len);
+        Self::unlink(front, back, len, list);
 
-        let mut res = LinkedList::new();
+        let mut res = list.new_sibling();
         res.init().splice_after(front, back, len, &mut res);
 
         res
@@ -256,16 +312,27 @@ impl<T> NodePtr<T> {
         self.is_dummy(list).not().then(|| {
             let Node {
                 prev, next, item, ..
-            } = self.dealloc_raw();
+            } = self.dealloc_raw_in(list.pool());
             (prev, item.assume_init(), next)
         })
     }
 
-    pub unsafe fn dealloc_unchecked(self) -> (Self, T, Self) {
-        let Node { prev, next, item } = self.dealloc_raw();
+    pub unsafe fn dealloc_unchecked(self, pool: Option<&RawPool>) -> (Self, T, Self) {
+        let Node { prev, next, item } = self.dealloc_raw_in(pool);
         (prev, item.assume_init(), next)
     }
 
+    // hands the allocation over to `pool` instead of freeing it, if there is one
+    pub unsafe fn dealloc_raw_in(self, pool: Option<&RawPool>) -> Node<T> {
+        let Some(pool) = pool else {
+            return self.dealloc_raw();
+        };
+
+        let node = self.as_ptr().read();
+        pool.give(self.ptr.cast());
+        node
+    }
+
     pub unsafe fn dealloc_raw(self) -> Node<T> {
         #[cfg(feature = "debug-alloc")]
         {
@@ -0,0 +1,304 @@
+use std::any::Any;
+
+use super::LinkedList;
+
+/// Receives notifications about structural changes to a [`LinkedList`].
+///
+/// Positions are indices into the list at the time of the change. Every method has an empty
+/// default body, so implementors only override the events they care about. The element type is
+/// a parameter so that an observer can be written for lists of one type only.
+pub trait ListObserver<T> {
+    /// A single element was inserted at `index`.
+    fn on_insert(&mut self, index: usize) {
+        let _ = index;
+    }
+
+    /// `len` elements starting at `index` were removed.
+    fn on_remove(&mut self, index: usize, len: usize) {
+        let _ = (index, len);
+    }
+
+    /// `len` elements from another list were linked in starting at `index`.
+    fn on_splice(&mut self, index: usize, len: usize) {
+        let _ = (index, len);
+    }
+
+    /// All `len` elements of the list were removed.
+    fn on_clear(&mut self, len: usize) {
+        let _ = len;
+    }
+
+    /// The elements were rearranged without changing the length of the list.
+    fn on_reorder(&mut self) {}
+}
+
+// An observer as a list holds it. The element type is erased, so that lists stay covariant in
+// `T`: the box holds the observer, and `notify` is `notify_as::<T, O>` for the observer's type
+// `O`, which knows which `ListObserver<T>` impl to call.
+pub(crate) struct BoxedObserver {
+    observer: Box<dyn Any + Send>,
+    notify: fn(&mut dyn Any, Event),
+}
+
+fn notify_as<T, O: ListObserver<T> + 'static>(observer: &mut dyn Any, event: Event) {
+    let Some(observer) = observer.downcast_mut::<O>() else {
+        unreachable!("an observer is always notified as its own type");
+    };
+
+    match event {
+        Event::Insert(index) => observer.on_insert(index),
+        Event::Remove(_, 0) | Event::Splice(_, 0) | Event::Clear(0) => {}
+        Event::Remove(index, len) => observer.on_remove(index, len),
+        Event::Splice(index, len) => observer.on_splice(index, len),
+        Event::Clear(len) => observer.on_clear(len),
+        Event::Reorder => observer.on_reorder(),
+    }
+}
+
+impl<T, O: ListObserver<T> + ?Sized> ListObserver<T> for Box<O> {
+    fn on_insert(&mut self, index: usize) {
+        (**self).on_insert(index);
+    }
+
+    fn on_remove(&mut self, index: usize, len: usize) {
+        (**self).on_remove(index, len);
+    }
+
+    fn on_splice(&mut self, index: usize, len: usize) {
+        (**self).on_splice(index, len);
+    }
+
+    fn on_clear(&mut self, len: usize) {
+        (**self).on_clear(len);
+    }
+
+    fn on_reorder(&mut self) {
+        (**self).on_reorder();
+    }
+}
+
+// what `LinkedList::take_observer` hands back
+impl<T> ListObserver<T> for BoxedObserver {
+    fn on_insert(&mut self, index: usize) {
+        (self.notify)(&mut *self.observer, Event::Insert(index));
+    }
+
+    fn on_remove(&mut self, index: usize, len: usize) {
+        (self.notify)(&mut *self.observer, Event::Remove(index, len));
+    }
+
+    fn on_splice(&mut self, index: usize, len: usize) {
+        (self.notify)(&mut *self.observer, Event::Splice(index, len));
+    }
+
+    fn on_clear(&mut self, len: usize) {
+        (self.notify)(&mut *self.observer, Event::Clear(len));
+    }
+
+    fn on_reorder(&mut self) {
+        (self.notify)(&mut *self.observer, Event::Reorder);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Event {
+    Insert(usize),
+    Remove(usize, usize),
+    Splice(usize, usize),
+    Clear(usize),
+    Reorder,
+}
+
+impl<T> LinkedList<T> {
+    /// Replaces the list's observer. The observer is only ever called through `&mut self`, so
+    /// it doesn't need to be `Sync`. It moves with the list, so it needs to be `Send` for the
+    /// list to be, and it's owned by a list that has no lifetime to borrow it for, so it needs
+    /// to be `'static`; the elements don't.
+    pub fn set_observer<O: ListObserver<T> + Send + 'static>(&mut self, observer: O) {
+        self.observer = Some(BoxedObserver {
+            observer: Box::new(observer),
+            notify: notify_as::<T, O>,
+        });
+    }
+
+    pub fn take_observer(&mut self) -> Option<Box<dyn ListObserver<T> + Send>> {
+        let observer = self.observer.take()?;
+        Some(Box::new(observer))
+    }
+
+    pub fn has_observer(&self) -> bool {
+        self.observer.is_some()
+    }
+
+    pub(crate) fn notify(&mut self, event: Event) {
+        self.marks.adjust(event, self.len);
+
+        if let Some(BoxedObserver { observer, notify }) = &mut self.observer {
+            notify(&mut **observer, event);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{
+        cell::Cell,
+        sync::{Arc, Mutex},
+    };
+
+    use super::{Event, ListObserver};
+    use crate::sixth::LinkedList;
+
+    #[derive(Default, Clone)]
+    pub(crate) struct Recorder(Arc<Mutex<Vec<Event>>>);
+
+    impl Recorder {
+        pub(crate) fn take(&self) -> Vec<Event> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    impl<T> ListObserver<T> for Recorder {
+        fn on_insert(&mut self, index: usize) {
+            self.0.lock().unwrap().push(Event::Insert(index));
+        }
+
+        fn on_remove(&mut self, index: usize, len: usize) {
+            self.0.lock().unwrap().push(Event::Remove(index, len));
+        }
+
+        fn on_splice(&mut self, index: usize, len: usize) {
+            self.0.lock().unwrap().push(Event::Splice(index, len));
+        }
+
+        fn on_clear(&mut self, len: usize) {
+            self.0.lock().unwrap().push(Event::Clear(len));
+        }
+
+        fn on_reorder(&mut self) {
+            self.0.lock().unwrap().push(Event::Reorder);
+        }
+    }
+
+    #[test]
+    fn test_observer_push_pop() {
+        let recorder = Recorder::default();
+        let mut list = LinkedList::new();
+        list.set_observer(recorder.clone());
+        assert!(list.has_observer());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(
+            recorder.take(),
+            [Event::Insert(0), Event::Insert(1), Event::Insert(0)]
+        );
+
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(recorder.take(), [Event::Remove(2, 1), Event::Remove(0, 1)]);
+
+        list.clear();
+        list.clear();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(recorder.take(), [Event::Clear(1)]);
+
+        assert!(list.take_observer().is_some());
+        list.push_back(3);
+        assert!(recorder.take().is_empty());
+    }
+
+    // counts the elements of a list of strings, which a non-`Sync` observer can do just as well
+    struct Count(Cell<usize>);
+
+    impl ListObserver<String> for Count {
+        fn on_insert(&mut self, _: usize) {
+            self.0.set(self.0.get() + 1);
+        }
+
+        fn on_remove(&mut self, _: usize, len: usize) {
+            self.0.set(self.0.get() - len);
+        }
+    }
+
+    #[test]
+    fn test_observer_typed() {
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        let mut list = LinkedList::new();
+        list.set_observer(Count(Cell::new(0)));
+        assert_sync(&list);
+        list.push_back("a".to_string());
+        list.push_back("b".to_string());
+        list.pop_front();
+
+        // taken off one list, the observer can be set on another
+        let observer = list.take_observer().unwrap();
+        let mut other = LinkedList::new();
+        other.set_observer(observer);
+        other.push_back("c".to_string());
+        assert!(other.has_observer());
+
+        // the elements don't have to be 'static for the list to be observed
+        let words = String::from("a b c");
+        let recorder = Recorder::default();
+        let mut borrowed = LinkedList::new();
+        borrowed.set_observer(recorder.clone());
+        borrowed.push_back(&words[..1]);
+        assert_eq!(recorder.take(), [Event::Insert(0)]);
+    }
+
+    #[test]
+    fn test_observer_splice() {
+        let recorder = Recorder::default();
+        let mut list: LinkedList<i32> = (0..2).collect();
+        list.set_observer(recorder.clone());
+
+        list.extend_from_slice(&[2, 3, 4]);
+        list.extend_from_slice(&[]);
+        assert_eq!(recorder.take(), [Event::Splice(2, 3)]);
+    }
+
+    #[test]
+    fn test_observer_extend() {
+        let recorder = Recorder::default();
+        let mut list: LinkedList<i32> = (0..2).collect();
+        list.set_observer(recorder.clone());
+
+        list.extend(2..5);
+        list.extend(None::<i32>);
+        assert_eq!(recorder.take(), [Event::Splice(2, 3)]);
+
+        // what was appended before the iterator panicked is still reported
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.extend((5..10).map(|x| if x < 7 { x } else { panic!("source failed") }))
+        }));
+        assert!(result.is_err());
+        assert_eq!(recorder.take(), [Event::Splice(5, 2)]);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_observer_drain_filter() {
+        let recorder = Recorder::default();
+        let mut list = (0..6).collect::<LinkedList<_>>();
+        list.set_observer(recorder.clone());
+
+        let mut drain = list.drain_filter(|x| *x % 2 == 1);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(5));
+        assert_eq!(drain.next(), Some(3));
+        assert_eq!(drain.next(), None);
+
+        assert_eq!(
+            recorder.take(),
+            [
+                Event::Remove(1, 1),
+                Event::Remove(4, 1),
+                Event::Remove(2, 1)
+            ]
+        );
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), [0, 2, 4]);
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    cmp::Ordering,
+    panic::{self, AssertUnwindSafe},
+};
+
+use rayon::prelude::*;
+
+use super::{observer::Event, LinkedList};
+
+// segments shorter than this are not worth handing to another thread
+const MIN_SEGMENT_LEN: usize = 1 << 10;
+
+impl<T: Send> LinkedList<T> {
+    pub fn par_sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sort_by(T::cmp)
+    }
+
+    /// Stable parallel merge sort. The list is cut into one segment per worker thread, the
+    /// segments are sorted concurrently, and the sorted segments are merged back together by
+    /// relinking nodes.
+    ///
+    /// If `cmp` panics on any thread, the list keeps all of its elements, in an unspecified
+    /// order, and the panic is resumed on the calling thread.
+    pub fn par_sort_by<F>(&mut self, cmp: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let parts = rayon::current_num_threads().min(self.len / MIN_SEGMENT_LEN);
+        if parts < 2 {
+            self.sort_by(&cmp);
+            return;
+        }
+
+        // the nodes are moved out to be sorted and back in wholesale, which observers should see
+        // as a single reorder rather than a series of removals and splices
+        let mut list = self.new_sibling();
+        let dummy = list.init();
+        unsafe { list.transfer_all(dummy, self) };
+        let mut segments = list.split_n(parts);
+
+        // a panicking comparison on a worker is caught so that every segment can be put back
+        // into the list before the panic carries on
+        let mut panics: Vec<_> = segments
+            .par_iter_mut()
+            .filter_map(|segment| {
+                panic::catch_unwind(AssertUnwindSafe(|| segment.merge_sort_by(&cmp))).err()
+            })
+            .collect();
+        while segments.len() > 1 && panics.is_empty() {
+            panics = segments
+                .par_chunks_mut(2)
+                .filter_map(|pair| match pair {
+                    [left, right] => {
+                        panic::catch_unwind(AssertUnwindSafe(|| left.merge_by(right, &cmp))).err()
+                    }
+                    _ => None,
+                })
+                .collect();
+            segments.retain(|segment| !segment.is_empty());
+        }
+
+        let dummy = self.init();
+        for segment in &mut segments {
+            unsafe { self.transfer_all(dummy, segment) };
+        }
+        self.notify(Event::Reorder);
+        if let Some(payload) = panics.pop() {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use rayon::{ThreadPool, ThreadPoolBuilder};
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
+
+    // makes sure the segments really are split up, however many cores the machine has
+    fn pool() -> ThreadPool {
+        ThreadPoolBuilder::new().num_threads(4).build().unwrap()
+    }
+
+    #[test]
+    fn test_par_sort() {
+        let items: Vec<u64> = (0..20_000u64)
+            .map(|i| (i * 2_654_435_761) % 10_007)
+            .collect();
+        let mut list: LinkedList<u64> = items.iter().copied().collect();
+        pool().install(|| list.par_sort());
+
+        let mut expected = items;
+        expected.sort();
+        assert_eq!(list.len(), expected.len());
+        assert!(list.iter().eq(&expected));
+        assert!(list.iter().rev().eq(expected.iter().rev()));
+    }
+
+    #[test]
+    fn test_par_sort_by_stable() {
+        let mut list: LinkedList<(u32, u32)> = (0..10_000).map(|i| (i % 7, i)).collect();
+        pool().install(|| list.par_sort_by(|a, b| a.0.cmp(&b.0)));
+
+        let mut expected = list.iter().copied().collect::<Vec<_>>();
+        expected.sort();
+        assert!(list.iter().eq(&expected));
+    }
+
+    #[test]
+    fn test_par_sort_keeps_list_state() {
+        let mut list: LinkedList<u32> = (0..10_000).rev().collect();
+        list.set_debug_name("jobs");
+        let id = list.debug_id();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let end = list.set_mark("end", 10_000);
+
+        pool().install(|| list.par_sort());
+        assert!(list.iter().eq(&(0..10_000).collect::<Vec<_>>()));
+        assert_eq!(list.debug_id(), id);
+        if let Some(id) = id {
+            assert_eq!(list.label().to_string(), format!("list #{} \"jobs\"", id));
+        }
+        assert_eq!(list.mark_index(end), Ok(10_000));
+        assert_eq!(events.take(), [Event::Reorder]);
+        list.validate();
+    }
+
+    #[test]
+    fn test_par_sort_panic() {
+        let mut list: LinkedList<String> = (0..10_000).rev().map(|i| i.to_string()).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let end = list.set_mark("end", 10_000);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            pool().install(|| {
+                list.par_sort_by(|a, b| {
+                    if a == "5000" || b == "5000" {
+                        panic!("comparison failed");
+                    }
+                    a.cmp(b)
+                })
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 10_000);
+        let mut items: Vec<u32> = list.iter().map(|item| item.parse().unwrap()).collect();
+        items.sort_unstable();
+        assert!(items.into_iter().eq(0..10_000));
+        assert_eq!(list.mark_index(end), Ok(10_000));
+        assert_eq!(events.take(), [Event::Reorder]);
+        list.validate();
+    }
+
+    #[test]
+    fn test_par_sort_single() {
+        let mut list = LinkedList::from([1]);
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let front = list.set_mark("front", 0);
+
+        list.par_sort();
+        assert_eq!(list.mark_index(front), Ok(0));
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    fn test_par_sort_small() {
+        let mut list: LinkedList<i32> = [3, 1, 2].into_iter().collect();
+        list.par_sort();
+        assert!(list.iter().eq(&[1, 2, 3]));
+
+        let mut list = LinkedList::<i32>::new();
+        list.par_sort();
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+
+use super::{node::NodePtr, observer::Event, LinkedList};
+
+impl<T> LinkedList<T> {
+    /// Splits the list into the elements for which `f` returns `Less`, `Equal` and `Greater`,
+    /// in a single pass that relinks nodes into the three buckets. Relative order is preserved
+    /// within each bucket.
+    pub fn partition3<F: FnMut(&T) -> Ordering>(
+        mut self,
+        mut f: F,
+    ) -> (LinkedList<T>, LinkedList<T>, LinkedList<T>) {
+        let mut less = self.new_sibling();
+        let mut greater = self.new_sibling();
+
+        if let Some(iter) = unsafe { self.raw_iter() } {
+            let mut kept = 0;
+            for node in iter {
+                let bucket = match f(unsafe { node.get_unchecked() }) {
+                    Ordering::Less => &mut less,
+                    Ordering::Equal => {
+                        kept += 1;
+                        continue;
+                    }
+                    Ordering::Greater => &mut greater,
+                };
+                let at = bucket.init();
+                unsafe { bucket.transfer(at, &mut self, node, node, 1) };
+                self.notify(Event::Remove(kept, 1));
+            }
+        }
+
+        (less, self, greater)
+    }
+
+    /// Splits the list into the elements for which `pred` returns true and those for which it
+    /// returns false, by relinking nodes. Relative order is preserved in both lists.
+    pub fn partition<P: FnMut(&T) -> bool>(
+        mut self,
+        mut pred: P,
+    ) -> (LinkedList<T>, LinkedList<T>) {
+        let mut matching = self.new_sibling();
+
+        if let Some(iter) = unsafe { self.raw_iter() } {
+            let mut kept = 0;
+            for node in iter {
+                if !pred(unsafe { node.get_unchecked() }) {
+                    kept += 1;
+                    continue;
+                }
+                let at = matching.init();
+                unsafe { matching.transfer(at, &mut self, node, node, 1) };
+                self.notify(Event::Remove(kept, 1));
+            }
+        }
+
+        (matching, self)
+    }
+
+    /// Moves the elements for which `pred` returns true in front of the rest, keeping the
+    /// relative order of both groups, and returns how many there are. Matching nodes are
+    /// relinked one by one, so the list is whole even if `pred` panics.
+    pub fn partition_in_place<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> usize {
+        let (Some(dummy), Some(iter)) = (self.dummy, unsafe { self.raw_iter() }) else {
+            return 0;
+        };
+
+        // the last matching node, after which the next one goes
+        let mut boundary = dummy;
+        let mut matched = 0;
+        let mut moved = false;
+        for node in iter {
+            if !pred(unsafe { node.get_unchecked() }) {
+                continue;
+            }
+
+            if boundary.next() != node {
+                unsafe {
+                    NodePtr::unlink(node, node, 1, self);
+                    boundary.splice_after(node, node, 1, self);
+                }
+                moved = true;
+            }
+            boundary = node;
+            matched += 1;
+        }
+
+        if moved {
+            self.notify(Event::Reorder);
+        }
+        matched
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic;
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
+
+    #[test]
+    fn test_partition() {
+        let list: LinkedList<i32> = (0..10).collect();
+        let front = list.iter().next().map(|x| x as *const i32);
+        let (even, odd) = list.partition(|x| x % 2 == 0);
+
+        assert_eq!(even, [0, 2, 4, 6, 8]);
+        assert_eq!(odd, [1, 3, 5, 7, 9]);
+        assert!(odd.iter().rev().eq(&[9, 7, 5, 3, 1]));
+        assert_eq!(even.iter().next().map(|x| x as *const i32), front);
+        even.validate();
+        odd.validate();
+
+        let (all, none) = LinkedList::from([1, 2]).partition(|_| true);
+        assert_eq!((all.len(), none.len()), (2, 0));
+    }
+
+    #[test]
+    fn test_partition_in_place() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        assert_eq!(list.partition_in_place(|x| x % 3 == 0), 4);
+        assert_eq!(list, [0, 3, 6, 9, 1, 2, 4, 5, 7, 8]);
+        assert!(list.iter().rev().eq(&[8, 7, 5, 4, 2, 1, 9, 6, 3, 0]));
+        assert_eq!(events.take(), [Event::Reorder]);
+
+        // already partitioned, so nothing moves
+        assert_eq!(list.partition_in_place(|x| x % 3 == 0), 4);
+        assert_eq!(list.partition_in_place(|_| false), 0);
+        assert!(events.take().is_empty());
+        assert_eq!(LinkedList::<i32>::new().partition_in_place(|_| true), 0);
+    }
+
+    #[test]
+    fn test_partition_in_place_panic() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.partition_in_place(|x| {
+                if *x == 5 {
+                    panic!("predicate failed")
+                } else {
+                    x % 2 == 1
+                }
+            })
+        }));
+
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(list, [1, 3, 0, 2, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_partition3() {
+        let list: LinkedList<i32> = [5, 1, 9, 5, 3, 7, 5, 0].into_iter().collect();
+        let (less, equal, greater) = list.partition3(|x| x.cmp(&5));
+
+        assert!(less.iter().eq(&[1, 3, 0]));
+        assert!(equal.iter().eq(&[5, 5, 5]));
+        assert!(greater.iter().eq(&[9, 7]));
+        assert_eq!((less.len(), equal.len(), greater.len()), (3, 3, 2));
+        assert!(greater.iter().rev().eq(&[7, 9]));
+    }
+
+    #[test]
+    fn test_partition3_single_bucket() {
+        let list: LinkedList<i32> = (0..4).collect();
+        let (less, equal, greater) = list.partition3(|_| std::cmp::Ordering::Greater);
+        assert!(less.is_empty() && equal.is_empty());
+        assert!(greater.iter().eq(&[0, 1, 2, 3]));
+
+        let (less, equal, greater) = LinkedList::<i32>::new().partition3(|x| x.cmp(&0));
+        assert!(less.is_empty() && equal.is_empty() && greater.is_empty());
+    }
+}
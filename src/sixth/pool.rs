@@ -0,0 +1,277 @@
+use std::{
+    alloc::{self, Layout},
+    fmt::Debug,
+    marker::PhantomData,
+    ptr::NonNull,
+    sync::{Arc, Mutex},
+};
+
+use super::{node::Node, LinkedList};
+
+/// A cache of freed node allocations that any number of lists of the same element type can
+/// share.
+///
+/// A list created with [`LinkedList::new_in_pool`] returns the nodes it frees to the pool and
+/// takes nodes from the pool before asking the allocator for new ones, so lists that churn
+/// elements reuse each other's allocations. Pooled nodes are ordinary heap allocations: lists
+/// can still exchange nodes with lists in other pools or in no pool at all. The pool only frees
+/// its cached allocations when [`NodePool::release`] is called or the last handle to it is
+/// dropped.
+pub struct NodePool<T> {
+    raw: Arc<RawPool>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+// The pool as lists hold on to it. It's type-erased so that lists stay covariant in `T`; every
+// block in it has the layout of the `Node<T>` it was created for.
+pub(crate) struct RawPool {
+    free: Mutex<Vec<FreeBlock>>,
+    layout: Layout,
+}
+
+struct FreeBlock(NonNull<u8>);
+
+// Safety: a free block is unused memory
+unsafe impl Send for FreeBlock {}
+
+impl RawPool {
+    pub(crate) fn take(&self) -> Option<NonNull<u8>> {
+        self.free.lock().unwrap().pop().map(|block| block.0)
+    }
+
+    // `block` must be an allocation with the pool's layout that nothing else refers to
+    pub(crate) unsafe fn give(&self, block: NonNull<u8>) {
+        self.free.lock().unwrap().push(FreeBlock(block));
+    }
+
+    fn release(&self) {
+        let free = std::mem::take(&mut *self.free.lock().unwrap());
+        for block in free {
+            unsafe { alloc::dealloc(block.0.as_ptr(), self.layout) };
+        }
+    }
+}
+
+impl Drop for RawPool {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+impl<T> Default for NodePool<T> {
+    fn default() -> Self {
+        Self {
+            raw: Arc::new(RawPool {
+                free: Mutex::new(Vec::new()),
+                layout: Layout::new::<Node<T>>(),
+            }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> NodePool<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The number of cached allocations ready to be reused.
+    pub fn available(&self) -> usize {
+        self.raw.free.lock().unwrap().len()
+    }
+
+    /// Allocates `additional` nodes up front and adds them to the pool.
+    pub fn reserve(&self, additional: usize) {
+        let layout = self.raw.layout;
+        let mut free = self.raw.free.lock().unwrap();
+        free.reserve(additional);
+        for _ in 0..additional {
+            let block = unsafe { alloc::alloc(layout) };
+            let Some(block) = NonNull::new(block) else {
+                alloc::handle_alloc_error(layout);
+            };
+            free.push(FreeBlock(block));
+        }
+    }
+
+    /// Frees every cached allocation. Lists in the pool keep using it afterwards.
+    pub fn release(&self) {
+        self.raw.release();
+    }
+}
+
+impl<T> Clone for NodePool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for NodePool<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodePool")
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Creates an empty list that allocates its nodes from `pool` and returns them to it.
+    pub fn new_in_pool(pool: &NodePool<T>) -> Self {
+        let mut list = LinkedList::new();
+        list.pool = Some(pool.raw.clone());
+        list
+    }
+
+    pub(crate) fn pool(&self) -> Option<&RawPool> {
+        self.pool.as_deref()
+    }
+
+    // an empty list in the same pool as self
+    pub(crate) fn new_sibling(&self) -> Self {
+        let mut list = LinkedList::new();
+        list.pool = self.pool.clone();
+        list
+    }
+
+    // an empty list of `U` in the same pool as self, if the pool's blocks fit a `Node<U>`, and
+    // in no pool otherwise
+    pub(crate) fn new_sibling_of<U>(&self) -> LinkedList<U> {
+        let mut list = LinkedList::new();
+        if Node::<T>::same_layout::<U>() {
+            list.pool = self.pool.clone();
+        }
+        list
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::NodePool;
+    use crate::sixth::LinkedList;
+
+    #[test]
+    fn test_pool_reuse() {
+        let pool = NodePool::new();
+        let mut a = LinkedList::new_in_pool(&pool);
+        let mut b = LinkedList::new_in_pool(&pool);
+
+        a.extend(0..10);
+        assert_eq!(pool.available(), 0);
+        for _ in 0..4 {
+            a.pop_front();
+        }
+        assert_eq!(pool.available(), 4);
+
+        // b's dummy node and its first three elements come out of the pool
+        b.extend([10, 11, 12, 13]);
+        assert_eq!(pool.available(), 0);
+        assert!(a.iter().eq(&[4, 5, 6, 7, 8, 9]));
+        assert!(b.iter().eq(&[10, 11, 12, 13]));
+
+        drop(a);
+        assert_eq!(pool.available(), 7);
+        b.clear();
+        assert_eq!(pool.available(), 11);
+        drop(b);
+        assert_eq!(pool.available(), 12);
+
+        pool.release();
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_pool_mixed_lists() {
+        let pool = NodePool::new();
+        pool.reserve(3);
+        assert_eq!(pool.available(), 3);
+
+        let mut pooled = LinkedList::new_in_pool(&pool);
+        pooled.extend(["c", "d", "e"].map(String::from));
+        assert_eq!(pool.available(), 0);
+
+        // nodes move freely between pooled and unpooled lists
+        let mut plain: LinkedList<String> = ["a", "b"].map(String::from).into_iter().collect();
+        let mut tail = pooled.split_off_back(2);
+        plain.splice_back(&mut tail);
+        assert!(plain.iter().eq(["a", "b", "d", "e"]));
+
+        drop(tail);
+        drop(plain);
+        assert_eq!(pool.available(), 1);
+        drop(pooled);
+        assert_eq!(pool.available(), 3);
+    }
+
+    #[test]
+    fn test_pool_split_results() {
+        let pool = NodePool::new();
+        let mut list = LinkedList::new_in_pool(&pool);
+        list.extend(0..6u64);
+
+        // even the lists that come out empty allocate from the pool once they're filled
+        assert!(list.pop_front_while(|&x| x > 10).pool.is_some());
+        assert!(list.pop_back_n(0).pool.is_some());
+        assert!(list.split_off_back(1).pool.is_some());
+        assert!(list.detach_range(1..1).1.pool.is_some());
+        assert!(list.clone().map(|x| x as i64).pool.is_some());
+        let list = list.split_for_parallel(2).rejoin();
+        assert!(list.pool.is_some());
+        let (less, equal, greater) = list.partition3(|x| x.cmp(&3));
+        assert!(less.pool.is_some() && equal.pool.is_some() && greater.pool.is_some());
+
+        // halves only stay in the pool if their nodes fit its blocks
+        let mut pairs = LinkedList::new_in_pool(&NodePool::new());
+        pairs.extend([(1u64, ()), (2, ())]);
+        let (numbers, units) = pairs.unzip();
+        assert!(numbers.pool.is_some());
+        assert!(units.pool.is_none());
+    }
+
+    #[test]
+    fn test_pool_clone() {
+        let pool = NodePool::new();
+        let list = {
+            let mut list = LinkedList::new_in_pool(&pool);
+            list.extend(0..3);
+            list
+        };
+
+        let mut clone = list.clone();
+        drop(list);
+        assert_eq!(pool.available(), 4);
+        // the copies are built in a temporary list, whose dummy node goes back to the pool
+        clone.extend_from_within(..);
+        assert_eq!(pool.available(), 1);
+        assert!(clone.iter().eq(&[0, 1, 2, 0, 1, 2]));
+    }
+
+    #[test]
+    fn test_pool_across_threads() {
+        let pool = NodePool::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let pool = pool.clone();
+                thread::spawn(move || {
+                    let mut list = LinkedList::new_in_pool(&pool);
+                    for round in 0..100 {
+                        list.push_back(i * 100 + round);
+                        if round % 3 == 0 {
+                            list.pop_front();
+                        }
+                    }
+                    list.len()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 66);
+        }
+        assert!(pool.available() >= 4);
+    }
+}
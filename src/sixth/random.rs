@@ -0,0 +1,153 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{node::NodePtr, observer::Event, LinkedList};
+
+impl<T> LinkedList<T> {
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let index = rng.gen_range(0..self.len);
+        if index < self.len / 2 {
+            self.iter().nth(index)
+        } else {
+            self.iter().nth_back(self.len - 1 - index)
+        }
+    }
+
+    /// Picks `k` distinct elements (or every element, if there are fewer than `k`) in a single
+    /// pass using reservoir sampling. The order of the returned references is unspecified.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R, k: usize) -> Vec<&T> {
+        let mut reservoir = Vec::with_capacity(k.min(self.len));
+        for (i, item) in self.iter().enumerate() {
+            if i < k {
+                reservoir.push(item);
+            } else {
+                let j = rng.gen_range(0..=i);
+                if j < k {
+                    reservoir[j] = item;
+                }
+            }
+        }
+        reservoir
+    }
+
+    /// Shuffles the list in place by relinking its nodes in a uniformly random order.
+    ///
+    /// Uses a temporary buffer of node pointers, so no element is moved or reallocated.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        if self.len < 2 {
+            return;
+        }
+
+        let Some(mut nodes) = (unsafe { self.raw_iter() }).map(Vec::from_iter) else {
+            return;
+        };
+        nodes.shuffle(rng);
+
+        unsafe { self.relink(&nodes) };
+        self.notify(Event::Reorder);
+    }
+
+    // relinks the list so that its elements are `nodes`, in order
+    // `nodes` must contain every node of self exactly once
+    unsafe fn relink(&mut self, nodes: &[NodePtr<T>]) {
+        let Some(dummy) = self.dummy else {
+            return;
+        };
+
+        let last = nodes.iter().fold(dummy, |prev, &node| {
+            prev.link(node);
+            node
+        });
+        last.link(dummy);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList,
+    };
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut list: LinkedList<i32> = (0..100).collect();
+
+        list.shuffle(&mut rng);
+        assert_eq!(list.len(), 100);
+        assert!(list.iter().copied().ne(0..100));
+
+        let mut items = list.iter().copied().collect::<Vec<_>>();
+        assert!(list.iter().rev().eq(items.iter().rev()));
+        items.sort_unstable();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+
+        list.push_back(100);
+        list.push_front(-1);
+        assert_eq!(list.len(), 102);
+        assert_eq!(list.front(), Some(&-1));
+        assert_eq!(list.back(), Some(&100));
+    }
+
+    #[test]
+    fn test_choose() {
+        let mut rng = StdRng::seed_from_u64(2);
+        assert_eq!(LinkedList::<i32>::new().choose(&mut rng), None);
+
+        let list: LinkedList<i32> = (0..10).collect();
+        let mut seen = [false; 10];
+        for _ in 0..200 {
+            let item = *list.choose(&mut rng).unwrap();
+            seen[item as usize] = true;
+        }
+        assert!(seen.iter().all(|seen| *seen));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let list: LinkedList<i32> = (0..50).collect();
+
+        let mut sample = list.sample(&mut rng, 10);
+        assert_eq!(sample.len(), 10);
+        sample.sort_unstable();
+        sample.dedup();
+        assert_eq!(sample.len(), 10);
+
+        let mut all = list.sample(&mut rng, 100);
+        all.sort_unstable();
+        assert!(all.into_iter().eq(list.iter()));
+
+        assert!(list.sample(&mut rng, 0).is_empty());
+        assert!(LinkedList::<i32>::new().sample(&mut rng, 3).is_empty());
+    }
+
+    #[test]
+    fn test_shuffle_small() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut list = LinkedList::<i32>::new();
+        list.shuffle(&mut rng);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.shuffle(&mut rng);
+        assert!(list.iter().eq(&[1]));
+    }
+
+    #[test]
+    fn test_shuffle_notifies() {
+        let recorder = Recorder::default();
+        let mut list: LinkedList<i32> = (0..10).collect();
+        list.set_observer(recorder.clone());
+
+        list.shuffle(&mut StdRng::seed_from_u64(7));
+        assert_eq!(recorder.take(), [Event::Reorder]);
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    cmp::Ordering,
+    mem,
+    ops::{Range, RangeBounds},
+};
+
+use super::{node::NodePtr, observer::Event, resolve_range, LinkedList};
+
+// Splices a detached range back in before `at` once the operation on it is done, or if it
+// panics partway through, and tells observers the list was reordered either way.
+struct Rejoin<'a, T> {
+    list: &'a mut LinkedList<T>,
+    at: NodePtr<T>,
+    range: LinkedList<T>,
+}
+
+impl<'a, T> Drop for Rejoin<'a, T> {
+    fn drop(&mut self) {
+        unsafe { self.list.transfer_all(self.at, &mut self.range) };
+        self.list.notify(Event::Reorder);
+    }
+}
+
+impl<T> LinkedList<T> {
+    // Detaches the elements in `range` as a new list without notifying observers, and returns it
+    // along with the node the range used to end before. `range` must already be resolved.
+    pub(crate) fn detach_range(&mut self, range: Range<usize>) -> (NodePtr<T>, LinkedList<T>) {
+        self.init();
+        let front = self.walk_to(range.start, None).unwrap();
+        let at = self.walk_to(range.end, Some((front, range.start))).unwrap();
+
+        let detached = if range.is_empty() {
+            self.new_sibling()
+        } else {
+            unsafe { NodePtr::slice_off_as_list(front, at.prev(), range.len(), self) }
+        };
+        (at, detached)
+    }
+
+    // Detaches the elements in `range` as a temporary list, hands it to `f` and links the result
+    // back in where the range was. Only the positions within the range can change, so observers
+    // see a single reorder.
+    fn reorder_range<R: RangeBounds<usize>, F: FnOnce(&mut LinkedList<T>)>(
+        &mut self,
+        range: R,
+        f: F,
+    ) {
+        let range = resolve_range(range, self.len);
+        if range.len() < 2 {
+            return;
+        }
+
+        let (at, detached) = self.detach_range(range);
+        let mut rejoin = Rejoin {
+            list: self,
+            at,
+            range: detached,
+        };
+        f(&mut rejoin.range);
+    }
+
+    // reverses the ring by swapping the links of every node, the dummy node included
+    pub(crate) fn reverse_links(&mut self) {
+        let Some(dummy) = self.dummy else {
+            return;
+        };
+
+        let mut node = dummy;
+        loop {
+            let node_ref = unsafe { node.as_mut() };
+            mem::swap(&mut node_ref.prev, &mut node_ref.next);
+            node = node_ref.prev;
+            if node == dummy {
+                break;
+            }
+        }
+    }
+
+    /// Reverses the list in place in one pass, by swapping the links of every node.
+    pub fn reverse(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+
+        self.reverse_links();
+        self.notify(Event::Reorder);
+    }
+
+    /// Reverses the order of the elements in `range`, leaving the rest of the list in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like slice indexing does.
+    pub fn reverse_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        self.reorder_range(range, LinkedList::reverse_links);
+    }
+
+    pub fn sort_range<R: RangeBounds<usize>>(&mut self, range: R)
+    where
+        T: Ord,
+    {
+        self.sort_range_by(range, T::cmp)
+    }
+
+    /// Stably sorts the elements in `range`, leaving the rest of the list in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like slice indexing does.
+    pub fn sort_range_by<R: RangeBounds<usize>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        range: R,
+        cmp: F,
+    ) {
+        self.reorder_range(range, |list| list.merge_sort_by(cmp));
+    }
+
+    pub fn sort_range_by_key<R: RangeBounds<usize>, K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        self.sort_range_by(range, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Rotates the elements in `range` to the left by `mid`, so that the element `mid`
+    /// positions into the range becomes its first, like `slice::rotate_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `mid` is greater than the length of the range.
+    pub fn rotate_range<R: RangeBounds<usize>>(&mut self, range: R, mid: usize) {
+        let range = resolve_range(range, self.len);
+        assert!(
+            mid <= range.len(),
+            "cannot rotate a range of length {} by {}",
+            range.len(),
+            mid
+        );
+        if mid == 0 || mid == range.len() {
+            return;
+        }
+
+        self.reorder_range(range, |list| unsafe {
+            let dummy = list.dummy.unwrap_unchecked();
+            let front = dummy.next();
+            let back = (1..mid).fold(front, |node, _| node.next());
+            NodePtr::unlink(front, back, mid, list);
+            dummy.splice_before(front, back, mid, list);
+        });
+    }
+
+    /// Exchanges the elements in `a_range` of `a` with the elements in `b_range` of `b`. The two
+    /// segments are moved by relinking their endpoints, so apart from walking to the ranges
+    /// nothing depends on their lengths, and no element is moved or reallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds, like slice indexing does.
+    pub fn swap_segments<R: RangeBounds<usize>, S: RangeBounds<usize>>(
+        a: &mut LinkedList<T>,
+        a_range: R,
+        b: &mut LinkedList<T>,
+        b_range: S,
+    ) {
+        let a_range = resolve_range(a_range, a.len);
+        let b_range = resolve_range(b_range, b.len);
+        let (a_start, b_start) = (a_range.start, b_range.start);
+
+        let (a_at, mut a_segment) = a.detach_range(a_range);
+        let (b_at, mut b_segment) = b.detach_range(b_range);
+        let (a_len, b_len) = (a_segment.len, b_segment.len);
+        unsafe {
+            a.transfer_all(a_at, &mut b_segment);
+            b.transfer_all(b_at, &mut a_segment);
+        }
+
+        a.notify(Event::Remove(a_start, a_len));
+        a.notify(Event::Splice(a_start, b_len));
+        b.notify(Event::Remove(b_start, b_len));
+        b.notify(Event::Splice(b_start, a_len));
+    }
+
+    /// Moves the elements in `range` to the back of `dest`, in order, by relinking them as one
+    /// segment rather than yielding them one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like slice indexing does.
+    pub fn drain_to<R: RangeBounds<usize>>(&mut self, range: R, dest: &mut LinkedList<T>) {
+        let range = resolve_range(range, self.len);
+        let start = range.start;
+
+        let (_, mut drained) = self.detach_range(range);
+        self.notify(Event::Remove(start, drained.len));
+        dest.splice_back(&mut drained);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic;
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList, MarkError,
+    };
+
+    #[test]
+    fn test_reverse() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.reverse();
+        assert!(list.iter().eq(&[4, 3, 2, 1, 0]));
+        assert!(list.iter().rev().eq(&[0, 1, 2, 3, 4]));
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&0));
+        list.push_back(-1);
+        list.validate();
+        assert_eq!(events.take(), [Event::Reorder, Event::Insert(5)]);
+
+        let mut single: LinkedList<i32> = (0..1).collect();
+        single.set_observer(events.clone());
+        single.reverse();
+        LinkedList::<i32>::new().reverse();
+        assert!(single.iter().eq(&[0]));
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    fn test_reverse_range() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+
+        list.reverse_range(2..6);
+        assert!(list.iter().eq(&[0, 1, 5, 4, 3, 2, 6, 7]));
+        list.reverse_range(..);
+        assert!(list.iter().eq(&[7, 6, 2, 3, 4, 5, 1, 0]));
+        assert!(list.iter().rev().eq(&[0, 1, 5, 4, 3, 2, 6, 7]));
+        list.reverse_range(6..);
+        list.reverse_range(3..4);
+        assert!(list.iter().eq(&[7, 6, 2, 3, 4, 5, 0, 1]));
+        assert_eq!(list.len(), 8);
+    }
+
+    #[test]
+    fn test_sort_range() {
+        let mut list: LinkedList<i32> = [9, 4, 7, 1, 8, 2, 0, 5].into_iter().collect();
+
+        list.sort_range(1..5);
+        assert!(list.iter().eq(&[9, 1, 4, 7, 8, 2, 0, 5]));
+        list.sort_range_by(5.., |a, b| b.cmp(a));
+        assert!(list.iter().eq(&[9, 1, 4, 7, 8, 5, 2, 0]));
+        list.sort_range_by_key(..=2, |x| *x);
+        assert!(list.iter().eq(&[1, 4, 9, 7, 8, 5, 2, 0]));
+        assert!(list.iter().rev().eq(&[0, 2, 5, 8, 7, 9, 4, 1]));
+    }
+
+    #[test]
+    fn test_sort_range_panic() {
+        let mut list: LinkedList<i32> = (0..10).rev().collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let inside = list.set_mark("inside", 4);
+        let end = list.set_mark("end", 10);
+
+        let mut count = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.sort_range_by(2..8, |a, b| {
+                count += 1;
+                if count == 4 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            });
+        }));
+
+        // the range keeps its elements, in some order, and the rest of the list is untouched
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(list.len(), 10);
+        assert!(list.iter().take(2).eq(&[9, 8]));
+        assert!(list.iter().skip(8).eq(&[1, 0]));
+        let mut range: Vec<i32> = list.iter().skip(2).take(6).copied().collect();
+        range.sort();
+        assert_eq!(range, [2, 3, 4, 5, 6, 7]);
+        assert_eq!(events.take(), [Event::Reorder]);
+        assert_eq!(list.mark_index(inside), Err(MarkError::Reordered));
+        assert_eq!(list.mark_index(end), Ok(10));
+    }
+
+    #[test]
+    fn test_rotate_range() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+
+        list.rotate_range(1..6, 2);
+        assert!(list.iter().eq(&[0, 3, 4, 5, 1, 2, 6, 7]));
+        list.rotate_range(.., 7);
+        assert!(list.iter().eq(&[7, 0, 3, 4, 5, 1, 2, 6]));
+        list.rotate_range(2..4, 2);
+        list.rotate_range(2..4, 0);
+        assert!(list.iter().eq(&[7, 0, 3, 4, 5, 1, 2, 6]));
+        assert!(list.iter().rev().eq(&[6, 2, 1, 5, 4, 3, 0, 7]));
+    }
+
+    #[test]
+    fn test_swap_segments() {
+        let mut a: LinkedList<i32> = (0..6).collect();
+        let mut b: LinkedList<i32> = (10..13).collect();
+
+        LinkedList::swap_segments(&mut a, 1..4, &mut b, 2..);
+        assert!(a.iter().eq(&[0, 12, 4, 5]));
+        assert!(b.iter().eq(&[10, 11, 1, 2, 3]));
+        assert_eq!((a.len(), b.len()), (4, 5));
+        assert!(a.iter().rev().eq(&[5, 4, 12, 0]));
+        assert!(b.iter().rev().eq(&[3, 2, 1, 11, 10]));
+
+        // an empty range works as an insertion point
+        LinkedList::swap_segments(&mut a, 4..4, &mut b, ..2);
+        assert!(a.iter().eq(&[0, 12, 4, 5, 10, 11]));
+        assert!(b.iter().eq(&[1, 2, 3]));
+
+        let mut empty = LinkedList::new();
+        LinkedList::swap_segments(&mut empty, .., &mut b, ..);
+        assert!(empty.iter().eq(&[1, 2, 3]));
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_swap_segments_observed() {
+        let mut a: LinkedList<i32> = (0..6).collect();
+        let mut b: LinkedList<i32> = (10..13).collect();
+        let recorder = Recorder::default();
+        a.set_observer(recorder.clone());
+
+        LinkedList::swap_segments(&mut a, 1..4, &mut b, 2..);
+        assert_eq!(recorder.take(), [Event::Remove(1, 3), Event::Splice(1, 1)]);
+    }
+
+    #[test]
+    fn test_drain_to() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        let mut dest: LinkedList<i32> = [-1].into_iter().collect();
+        let recorder = Recorder::default();
+        list.set_observer(recorder.clone());
+
+        list.drain_to(2..5, &mut dest);
+        assert!(list.iter().eq(&[0, 1, 5, 6, 7]));
+        assert!(dest.iter().eq(&[-1, 2, 3, 4]));
+        assert!(dest.iter().rev().eq(&[4, 3, 2, -1]));
+        assert_eq!((list.len(), dest.len()), (5, 4));
+        assert_eq!(recorder.take(), [Event::Remove(2, 3)]);
+
+        list.drain_to(1..1, &mut dest);
+        assert_eq!(dest.len(), 4);
+        list.drain_to(.., &mut dest);
+        assert!(list.is_empty());
+        assert!(dest.iter().eq(&[-1, 2, 3, 4, 0, 1, 5, 6, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_range_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        list.rotate_range(2..4, 3);
+    }
+}
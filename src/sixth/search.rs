@@ -0,0 +1,453 @@
+use std::cmp::Ordering;
+
+use super::{cursor::RawCursor, Cursor, CursorMut, LinkedList};
+
+impl<T> LinkedList<T> {
+    // Finds the first element for which `pred` returns false, assuming the list is partitioned
+    // so that every element satisfying `pred` comes before every element that does not.
+    // Walks inwards from both ends at once, so it costs O(min(k, len - k)) for a boundary at k.
+    pub(crate) fn partition_point_raw(&self, mut pred: impl FnMut(&T) -> bool) -> RawCursor<T> {
+        let Some(dummy) = self.dummy else {
+            return RawCursor::new(self);
+        };
+
+        // everything before `lo` satisfies pred, everything from `hi` onwards does not
+        let (mut lo, mut hi) = (0, self.len);
+        let (mut front, mut back) = (dummy.next(), dummy.prev());
+
+        while lo < hi {
+            if !pred(unsafe { front.get_unchecked() }) {
+                break;
+            }
+            lo += 1;
+            front = front.next();
+
+            if lo == hi {
+                break;
+            }
+
+            if pred(unsafe { back.get_unchecked() }) {
+                lo = hi;
+                front = back.next();
+                break;
+            }
+            hi -= 1;
+            back = back.prev();
+        }
+
+        RawCursor::from_node(front, lo)
+    }
+
+    pub fn lower_bound(&self, x: &T) -> Cursor<'_, T>
+    where
+        T: Ord,
+    {
+        self.lower_bound_by(|item| item.cmp(x))
+    }
+
+    pub fn lower_bound_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Cursor<'_, T> {
+        let raw = self.partition_point_raw(|item| f(item) == Ordering::Less);
+        Cursor::from_raw(raw, self)
+    }
+
+    pub fn lower_bound_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Cursor<'_, T> {
+        self.lower_bound_by(|item| f(item).cmp(key))
+    }
+
+    pub fn upper_bound(&self, x: &T) -> Cursor<'_, T>
+    where
+        T: Ord,
+    {
+        self.upper_bound_by(|item| item.cmp(x))
+    }
+
+    pub fn upper_bound_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Cursor<'_, T> {
+        let raw = self.partition_point_raw(|item| f(item) != Ordering::Greater);
+        Cursor::from_raw(raw, self)
+    }
+
+    pub fn upper_bound_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Cursor<'_, T> {
+        self.upper_bound_by(|item| f(item).cmp(key))
+    }
+
+    pub fn search(&self, x: &T) -> Result<Cursor<'_, T>, Cursor<'_, T>>
+    where
+        T: Ord,
+    {
+        self.search_by(|item| item.cmp(x))
+    }
+
+    /// Searches a list sorted by `f`, which returns how each element compares to the target.
+    /// Returns `Ok` with a cursor at the first matching element, or `Err` with a cursor at the
+    /// position where a matching element could be inserted with
+    /// [`insert_before`](CursorMut::insert_before) to keep the list sorted. That position is
+    /// the ghost element if every element compares less.
+    ///
+    /// There is no random access, so this walks in from both ends like
+    /// [`lower_bound`](LinkedList::lower_bound) does, but the cursor makes the follow-up insert
+    /// or removal O(1).
+    pub fn search_by<F: FnMut(&T) -> Ordering>(
+        &self,
+        mut f: F,
+    ) -> Result<Cursor<'_, T>, Cursor<'_, T>> {
+        let cursor = self.lower_bound_by(&mut f);
+        match cursor.current() {
+            Some(item) if f(item) == Ordering::Equal => Ok(cursor),
+            _ => Err(cursor),
+        }
+    }
+
+    pub fn search_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Result<Cursor<'_, T>, Cursor<'_, T>> {
+        self.search_by(|item| f(item).cmp(key))
+    }
+
+    pub fn search_mut(&mut self, x: &T) -> Result<CursorMut<'_, T>, CursorMut<'_, T>>
+    where
+        T: Ord,
+    {
+        self.search_by_mut(|item| item.cmp(x))
+    }
+
+    /// Like [`search_by`](LinkedList::search_by), but returns a [`CursorMut`].
+    pub fn search_by_mut<F: FnMut(&T) -> Ordering>(
+        &mut self,
+        mut f: F,
+    ) -> Result<CursorMut<'_, T>, CursorMut<'_, T>> {
+        let (raw, found) = match self.search_by(&mut f) {
+            Ok(cursor) => (cursor.inner, true),
+            Err(cursor) => (cursor.inner, false),
+        };
+
+        let cursor = CursorMut::from_raw(raw, self);
+        if found {
+            Ok(cursor)
+        } else {
+            Err(cursor)
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns true.
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
+    }
+}
+
+impl<T> LinkedList<T> {
+    // scans once for the element that `better` prefers over every other, keeping the earlier
+    // element on ties unless `better` says otherwise
+    fn cursor_to_best(
+        &mut self,
+        mut better: impl FnMut(&T, &T) -> bool,
+    ) -> Option<CursorMut<'_, T>> {
+        let iter = unsafe { self.raw_iter() }?;
+
+        let mut best: Option<RawCursor<T>> = None;
+        for (index, node) in iter.enumerate() {
+            let replace = best.is_none_or(|best| unsafe {
+                better(node.get_unchecked(), best.node.unwrap().get_unchecked())
+            });
+            if replace {
+                best = Some(RawCursor::from_node(node, index));
+            }
+        }
+
+        Some(CursorMut::from_raw(best?, self))
+    }
+
+    /// Returns a cursor parked on the first minimum element, or `None` if the list is empty.
+    pub fn cursor_to_min(&mut self) -> Option<CursorMut<'_, T>>
+    where
+        T: Ord,
+    {
+        self.cursor_to_min_by(T::cmp)
+    }
+
+    pub fn cursor_to_min_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        mut cmp: F,
+    ) -> Option<CursorMut<'_, T>> {
+        self.cursor_to_best(|item, best| cmp(item, best) == Ordering::Less)
+    }
+
+    pub fn cursor_to_min_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        mut f: F,
+    ) -> Option<CursorMut<'_, T>> {
+        self.cursor_to_min_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns a cursor parked on the last maximum element, or `None` if the list is empty.
+    pub fn cursor_to_max(&mut self) -> Option<CursorMut<'_, T>>
+    where
+        T: Ord,
+    {
+        self.cursor_to_max_by(T::cmp)
+    }
+
+    pub fn cursor_to_max_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        mut cmp: F,
+    ) -> Option<CursorMut<'_, T>> {
+        self.cursor_to_best(|item, best| cmp(item, best) != Ordering::Less)
+    }
+
+    pub fn cursor_to_max_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        mut f: F,
+    ) -> Option<CursorMut<'_, T>> {
+        self.cursor_to_max_by(|a, b| f(a).cmp(&f(b)))
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn insert_sorted(&mut self, item: T) -> CursorMut<'_, T>
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(item, T::cmp)
+    }
+
+    /// Inserts `item` into a list sorted by `cmp`, after any elements equal to it, and returns
+    /// a cursor on the new element. Inserting more items through that cursor with
+    /// [`CursorMut::insert_sorted_by`] only walks from where the last one went, so feeding in
+    /// nearly ordered items costs amortized O(1) each.
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        item: T,
+        mut cmp: F,
+    ) -> CursorMut<'_, T> {
+        let raw = self.partition_point_raw(|x| cmp(x, &item) != Ordering::Greater);
+        let mut cursor = CursorMut::from_raw(raw, self);
+        cursor.insert_before(item);
+        cursor.move_prev();
+        cursor
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn insert_sorted(&mut self, item: T)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(item, T::cmp)
+    }
+
+    /// Inserts `item` into a list sorted by `cmp`, after any elements equal to it, and moves
+    /// the cursor onto it. The cursor's position is only a hint: the walk starts there and goes
+    /// whichever way `item` belongs, so the cost is proportional to how far it ends up.
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, item: T, mut cmp: F) {
+        let greater =
+            |x: Option<&mut T>, cmp: &mut F| x.is_none_or(|x| cmp(x, &item) == Ordering::Greater);
+
+        if greater(self.current(), &mut cmp) {
+            while self
+                .peek_prev()
+                .is_some_and(|x| cmp(x, &item) == Ordering::Greater)
+            {
+                self.move_prev();
+            }
+        } else {
+            while !greater(self.current(), &mut cmp) {
+                self.move_next();
+            }
+        }
+
+        self.insert_before(item);
+        self.move_prev();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::LinkedList;
+
+    #[test]
+    fn test_bounds() {
+        let list: LinkedList<i32> = [1, 2, 2, 2, 3, 5, 8, 8, 13].into_iter().collect();
+
+        for x in 0..15 {
+            let lower = list.iter().take_while(|item| **item < x).count();
+            let upper = list.iter().take_while(|item| **item <= x).count();
+
+            let cursor = list.lower_bound(&x);
+            assert_eq!(cursor.index().unwrap_or(list.len()), lower);
+            assert_eq!(cursor.current(), list.iter().nth(lower));
+            assert_eq!(
+                cursor.peek_prev(),
+                lower.checked_sub(1).and_then(|i| list.iter().nth(i))
+            );
+
+            let cursor = list.upper_bound(&x);
+            assert_eq!(cursor.index().unwrap_or(list.len()), upper);
+            assert_eq!(cursor.current(), list.iter().nth(upper));
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let list: LinkedList<i32> = [1, 2, 2, 2, 3, 5, 8, 8, 13].into_iter().collect();
+
+        for x in 0..15 {
+            let lower = list.iter().take_while(|item| **item < x).count();
+            match list.search(&x) {
+                Ok(cursor) => {
+                    assert!(list.contains(&x));
+                    assert_eq!(cursor.index(), Some(lower));
+                    assert_eq!(cursor.current(), Some(&x));
+                }
+                Err(cursor) => {
+                    assert!(!list.contains(&x));
+                    assert_eq!(cursor.index().unwrap_or(list.len()), lower);
+                }
+            }
+        }
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.search(&1).err().unwrap().index(), None);
+    }
+
+    #[test]
+    fn test_search_mut() {
+        let mut list: LinkedList<(i32, char)> =
+            [(1, 'a'), (4, 'b'), (9, 'c')].into_iter().collect();
+
+        for (key, c) in [(5, 'x'), (4, 'y'), (0, 'z'), (10, 'w')] {
+            match list.search_by_mut(|item| item.0.cmp(&key)) {
+                Ok(mut cursor) => cursor.current().unwrap().1 = c,
+                Err(mut cursor) => cursor.insert_before((key, c)),
+            }
+        }
+        assert!(list
+            .iter()
+            .eq(&[(0, 'z'), (1, 'a'), (4, 'y'), (5, 'x'), (9, 'c'), (10, 'w')]));
+
+        assert_eq!(
+            list.search_by_key(&9, |item| item.0).ok().unwrap().index(),
+            Some(4)
+        );
+        let mut ints: LinkedList<i32> = (0..5).collect();
+        assert_eq!(ints.search_mut(&3).ok().unwrap().remove_current(), Some(3));
+        assert!(ints.iter().eq(&[0, 1, 2, 4]));
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list: LinkedList<(i32, char)> = LinkedList::new();
+        for (i, c) in [(5, 'a'), (1, 'b'), (5, 'c'), (3, 'd'), (9, 'e'), (1, 'f')] {
+            let cursor = list.insert_sorted_by((i, c), |a, b| a.0.cmp(&b.0));
+            assert_eq!(cursor.as_cursor().current(), Some(&(i, c)));
+        }
+        assert!(list
+            .iter()
+            .eq(&[(1, 'b'), (1, 'f'), (3, 'd'), (5, 'a'), (5, 'c'), (9, 'e')]));
+        assert!(list.iter().rev().map(|x| x.0).eq([9, 5, 5, 3, 1, 1]));
+    }
+
+    #[test]
+    fn test_insert_sorted_with_hint() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut cursor = list.insert_sorted(10);
+        for x in [11, 13, 12, 14, 2, 20, 12, 0, 25] {
+            cursor.insert_sorted(x);
+            assert_eq!(cursor.as_cursor().current(), Some(&x));
+            assert!(cursor.as_cursor().peek_prev().is_none_or(|prev| *prev <= x));
+            assert!(cursor.as_cursor().peek_next().is_none_or(|next| *next > x));
+        }
+        assert!(list.iter().eq(&[0, 2, 10, 11, 12, 12, 13, 14, 20, 25]));
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let mut ghost = list.cursor_back_mut();
+        ghost.move_next();
+        ghost.insert_sorted(3);
+        assert_eq!(ghost.index(), Some(4));
+        assert!(list.iter().eq(&[0, 1, 2, 3, 3, 4]));
+    }
+
+    #[test]
+    fn test_contains_position() {
+        let list: LinkedList<i32> = [4, 8, 15, 16, 23, 42].into_iter().collect();
+        assert!(list.contains(&15));
+        assert!(!list.contains(&5));
+        assert_eq!(list.position(|x| x % 2 == 1), Some(2));
+        assert_eq!(list.position(|x| *x > 100), None);
+
+        let empty = LinkedList::<i32>::new();
+        assert!(!empty.contains(&0));
+        assert_eq!(empty.position(|_| true), None);
+    }
+
+    #[test]
+    fn test_cursor_to_min_max() {
+        let mut list: LinkedList<(i32, char)> = [(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (4, 'e')]
+            .into_iter()
+            .collect();
+
+        let mut cursor = list.cursor_to_min_by_key(|item| item.0).unwrap();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.remove_current(), Some((1, 'b')));
+        assert_eq!(cursor.current(), Some(&mut (4, 'c')));
+
+        let mut cursor = list.cursor_to_max_by_key(|item| item.0).unwrap();
+        assert_eq!(cursor.index(), Some(3));
+        cursor.current().unwrap().0 = 0;
+
+        let cursor = list.cursor_to_min().unwrap();
+        assert_eq!(cursor.index(), Some(3));
+
+        assert!(list.iter().eq(&[(3, 'a'), (4, 'c'), (1, 'd'), (0, 'e')]));
+        assert!(LinkedList::<i32>::new().cursor_to_max().is_none());
+    }
+
+    #[test]
+    fn test_bounds_empty() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.lower_bound(&1).index(), None);
+        assert_eq!(list.upper_bound(&1).current(), None);
+
+        list.push_back(1);
+        list.pop_back();
+        assert_eq!(list.lower_bound(&1).index(), None);
+        assert_eq!(list.upper_bound(&1).current(), None);
+    }
+
+    #[test]
+    fn test_bounds_by_key() {
+        let list: LinkedList<(i32, &str)> = [(1, "a"), (3, "b"), (3, "c"), (7, "d")]
+            .into_iter()
+            .collect();
+
+        let mut cursor = list.lower_bound_by_key(&3, |item| item.0);
+        assert_eq!(cursor.current(), Some(&(3, "b")));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&(1, "a")));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(3));
+
+        let cursor = list.upper_bound_by_key(&3, |item| item.0);
+        assert_eq!(cursor.current(), Some(&(7, "d")));
+        assert_eq!(cursor.index(), Some(3));
+    }
+}
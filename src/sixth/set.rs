@@ -0,0 +1,201 @@
+use std::{cmp::Ordering, collections::HashSet, hash::Hash};
+
+use super::{node::NodePtr, observer::Event, LinkedList};
+
+// Set operations on sorted lists. Each one is a single merge pass over both inputs that
+// relinks the surviving nodes into the result and drops the rest; nothing is allocated apart
+// from the result's dummy node. Equal elements are matched one-for-one and the element from
+// `self` is the one that is kept.
+impl<T> LinkedList<T> {
+    pub fn union(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.merge_sets(other, true, true, true)
+    }
+
+    pub fn intersection(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.merge_sets(other, false, true, false)
+    }
+
+    pub fn difference(self, other: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.merge_sets(other, true, false, false)
+    }
+
+    fn merge_sets(
+        mut self,
+        mut other: LinkedList<T>,
+        keep_left: bool,
+        keep_equal: bool,
+        keep_right: bool,
+    ) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut res = self.new_sibling();
+        let dummy = res.init();
+
+        let mut take = |list: &mut LinkedList<T>, keep: bool| {
+            let node = list.front_node().unwrap();
+            if keep {
+                unsafe { res.transfer(dummy, list, node, node, 1) };
+            } else {
+                list.pop_front();
+            }
+        };
+
+        while let (Some(a), Some(b)) = (self.front_node(), other.front_node()) {
+            match unsafe { a.get_unchecked().cmp(b.get_unchecked()) } {
+                Ordering::Less => take(&mut self, keep_left),
+                Ordering::Greater => take(&mut other, keep_right),
+                Ordering::Equal => {
+                    take(&mut self, keep_equal);
+                    take(&mut other, false);
+                }
+            }
+        }
+
+        for (list, keep) in [(&mut self, keep_left), (&mut other, keep_right)] {
+            if let (true, Some(front), Some(back)) = (keep, list.front_node(), list.back_node()) {
+                let len = list.len;
+                unsafe { res.transfer(dummy, list, front, back, len) };
+            }
+        }
+
+        res
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Removes every element equal to one earlier in the list, wherever it is, keeping the
+    /// first occurrence of each. Unlike a consecutive dedup, the list doesn't need to be sorted.
+    pub fn unique(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        // the kept elements stay where they are, so the set can borrow them in place
+        let mut seen = HashSet::new();
+        self.retain_first(|node| seen.insert(unsafe { node.get_unchecked() }));
+    }
+
+    pub fn unique_by_key<K: Hash + Eq, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        let mut seen = HashSet::new();
+        self.retain_first(|node| seen.insert(f(unsafe { node.get_unchecked() })));
+    }
+
+    fn retain_first(&mut self, mut first: impl FnMut(NodePtr<T>) -> bool) {
+        let Some(iter) = (unsafe { self.raw_iter() }) else {
+            return;
+        };
+
+        let mut kept = 0;
+        for node in iter {
+            if first(node) {
+                kept += 1;
+            } else {
+                unsafe { node.pop_unchecked(self) };
+                self.notify(Event::Remove(kept, 1));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::LinkedList;
+
+    fn list_from(v: &[i32]) -> LinkedList<i32> {
+        v.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = || list_from(&[1, 3, 4, 7, 9]);
+        let b = || list_from(&[2, 3, 7, 8, 10, 11]);
+
+        assert!(a().union(b()).iter().eq(&[1, 2, 3, 4, 7, 8, 9, 10, 11]));
+        assert!(a().intersection(b()).iter().eq(&[3, 7]));
+        assert!(a().difference(b()).iter().eq(&[1, 4, 9]));
+        assert!(b().difference(a()).iter().eq(&[2, 8, 10, 11]));
+
+        let union = a().union(b());
+        assert_eq!(union.len(), 9);
+        assert!(union.iter().rev().eq(&[11, 10, 9, 8, 7, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn test_set_operations_edge_cases() {
+        let empty = LinkedList::new;
+
+        assert!(list_from(&[1, 2]).union(empty()).iter().eq(&[1, 2]));
+        assert!(empty().union(list_from(&[1, 2])).iter().eq(&[1, 2]));
+        assert!(list_from(&[1, 2]).intersection(empty()).is_empty());
+        assert!(list_from(&[1, 2]).difference(empty()).iter().eq(&[1, 2]));
+        assert!(empty().difference(list_from(&[1, 2])).is_empty());
+
+        let a = list_from(&[1, 1, 2, 2, 2]);
+        let b = list_from(&[1, 2]);
+        assert!(a.union(b).iter().eq(&[1, 1, 2, 2, 2]));
+
+        let a = list_from(&[1, 1, 2, 2, 2]);
+        let b = list_from(&[1, 2]);
+        assert!(a.difference(b).iter().eq(&[1, 2, 2]));
+    }
+
+    #[test]
+    fn test_unique() {
+        let mut list = list_from(&[3, 1, 3, 2, 1, 1, 4, 2]);
+        list.unique();
+        assert!(list.iter().eq(&[3, 1, 2, 4]));
+        assert!(list.iter().rev().eq(&[4, 2, 1, 3]));
+        assert_eq!(list.len(), 4);
+
+        let mut list: LinkedList<(i32, char)> = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')]
+            .into_iter()
+            .collect();
+        list.unique_by_key(|item| item.0);
+        assert!(list.iter().map(|item| item.1).eq(['a', 'b', 'd']));
+
+        let mut list = LinkedList::<i32>::new();
+        list.unique();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations_keep_left() {
+        #[derive(Debug)]
+        struct Keyed(i32, char);
+
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Keyed {}
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let a: LinkedList<Keyed> = [Keyed(1, 'a'), Keyed(2, 'a')].into_iter().collect();
+        let b: LinkedList<Keyed> = [Keyed(1, 'b'), Keyed(3, 'b')].into_iter().collect();
+
+        let union = a.union(b);
+        assert!(union.iter().map(|k| k.1).eq(['a', 'a', 'b']));
+    }
+}
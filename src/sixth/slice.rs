@@ -0,0 +1,316 @@
+use std::{fmt::Debug, marker::PhantomData, ops::RangeBounds, ptr};
+
+use super::{iter::RawIter, resolve_range, Cursor, Iter, IterMut, LinkedList, Mark, MarkError};
+
+/// A shared view of a contiguous run of elements of a [`LinkedList`].
+pub struct ListSlice<'a, T> {
+    inner: Option<RawIter<T>>,
+    _phantom: PhantomData<&'a T>,
+}
+
+/// A mutable view of a contiguous run of elements of a [`LinkedList`]. The elements can be
+/// modified in place, but the view can't add or remove nodes.
+pub struct ListSliceMut<'a, T> {
+    inner: Option<RawIter<T>>,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<T> LinkedList<T> {
+    // the nodes at positions range.start..range.end, walking from the closer end for each bound
+    pub(crate) fn raw_slice<R: RangeBounds<usize>>(&self, range: R) -> Option<RawIter<T>> {
+        let range = resolve_range(range, self.len);
+        let front = self.walk_to(range.start, None)?;
+        let back = self.walk_to(range.end, Some((front, range.start)))?.prev();
+        Some(RawIter::new(front, back, range.len()))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like slice indexing does.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ListSlice<'_, T> {
+        ListSlice {
+            inner: self.raw_slice(range),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like slice indexing does.
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ListSliceMut<'_, T> {
+        ListSliceMut {
+            inner: self.raw_slice(range),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns an iterator over the elements from the one `start` points at up to, but not
+    /// including, the one `end` points at, without walking from either end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either cursor belongs to another list or `end` is before `start`.
+    pub fn range<'a>(&'a self, start: &Cursor<'a, T>, end: &Cursor<'a, T>) -> Iter<'a, T> {
+        assert!(
+            ptr::eq(self, start.list),
+            "cursors belong to different lists"
+        );
+        start.slice_to(end).iter()
+    }
+
+    /// Returns a mutable iterator over the elements from the one `start` marks up to, but not
+    /// including, the one `end` marks. Marks stand in for cursors here, since a cursor would
+    /// keep the list borrowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` is before `start`.
+    pub fn range_mut(&mut self, start: Mark, end: Mark) -> Result<IterMut<'_, T>, MarkError> {
+        let (start, end) = (self.mark_index(start)?, self.mark_index(end)?);
+        assert!(
+            start <= end,
+            "range starts at {} but ends at {}",
+            start,
+            end
+        );
+        Ok(self.slice_mut(start..end).into_iter())
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns a view of the elements from the current one up to, but not including, the one
+    /// `end` points at. If `end` is on the ghost element, the view runs to the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursors belong to different lists or `end` is before `self`.
+    pub fn slice_to(&self, end: &Cursor<'a, T>) -> ListSlice<'a, T> {
+        assert!(
+            ptr::eq(self.list, end.list),
+            "cursors belong to different lists"
+        );
+        assert!(
+            self.inner.index <= end.inner.index,
+            "slice starts at {} but ends at {}",
+            self.inner.index,
+            end.inner.index
+        );
+
+        let inner = self
+            .inner
+            .node
+            .zip(end.inner.node)
+            .map(|(front, end_node)| {
+                RawIter::new(front, end_node.prev(), end.inner.index - self.inner.index)
+            });
+        ListSlice {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> ListSlice<'a, T> {
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn front(&self) -> Option<&'a T> {
+        self.iter().next()
+    }
+
+    pub fn back(&self) -> Option<&'a T> {
+        self.iter().next_back()
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::from_raw(self.inner)
+    }
+}
+
+impl<'a, T> ListSliceMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next_back()
+    }
+
+    pub fn as_slice(&self) -> ListSlice<'_, T> {
+        ListSlice {
+            inner: self.inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::from_raw(self.inner)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::from_raw(self.inner)
+    }
+}
+
+impl<'a, T> Clone for ListSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ListSlice<'a, T> {}
+
+impl<'a, T> IntoIterator for ListSlice<'a, T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::from_raw(self.inner)
+    }
+}
+
+impl<'a, T> IntoIterator for ListSliceMut<'a, T> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::from_raw(self.inner)
+    }
+}
+
+impl<'a, T: Debug> Debug for ListSlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Debug> Debug for ListSliceMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl<'a, T: Sync> Send for ListSlice<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for ListSlice<'a, T> {}
+
+unsafe impl<'a, T: Send> Send for ListSliceMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for ListSliceMut<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::{LinkedList, MarkError};
+
+    #[test]
+    fn test_slice() {
+        let list: LinkedList<i32> = (0..10).collect();
+
+        let slice = list.slice(2..6);
+        assert_eq!(slice.len(), 4);
+        assert_eq!((slice.front(), slice.back()), (Some(&2), Some(&5)));
+        assert!(slice.iter().eq(&[2, 3, 4, 5]));
+        assert!(slice.iter().rev().eq(&[5, 4, 3, 2]));
+        assert_eq!(format!("{:?}", slice), "[2, 3, 4, 5]");
+
+        assert!(list.slice(..).iter().eq(&list));
+        assert!(list.slice(7..=9).into_iter().eq(&[7, 8, 9]));
+        assert!(list.slice(10..).is_empty());
+        assert!(list.slice(3..3).iter().next().is_none());
+        assert!(LinkedList::<i32>::new().slice(..).is_empty());
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+
+        let mut slice = list.slice_mut(5..);
+        *slice.front_mut().unwrap() = 50;
+        *slice.back_mut().unwrap() = 70;
+        slice.iter_mut().for_each(|x| *x += 1);
+        assert!(slice.as_slice().iter().eq(&[51, 7, 71]));
+
+        for x in list.slice_mut(..2) {
+            *x *= -1;
+        }
+        assert!(list.iter().eq(&[0, -1, 2, 3, 4, 51, 7, 71]));
+        assert_eq!(list.len(), 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let list: LinkedList<i32> = (0..3).collect();
+        list.slice(1..4);
+    }
+
+    #[test]
+    fn test_range() {
+        let list: LinkedList<i32> = (0..8).collect();
+        let (start, end) = (list.lower_bound(&2), list.lower_bound(&5));
+        assert!(list.range(&start, &end).eq(&[2, 3, 4]));
+        assert!(list.range(&start, &end).rev().eq(&[4, 3, 2]));
+        assert_eq!(list.range(&end, &end).len(), 0);
+
+        let ghost = list.lower_bound(&100);
+        assert!(list.range(&end, &ghost).eq(&[5, 6, 7]));
+    }
+
+    #[test]
+    #[should_panic = "cursors belong to different lists"]
+    fn test_range_other_list() {
+        let (a, b): (LinkedList<i32>, LinkedList<i32>) = ((0..3).collect(), (0..3).collect());
+        a.range(&b.cursor_front(), &b.cursor_back());
+    }
+
+    #[test]
+    fn test_range_mut() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        let start = list.set_mark("start", 2);
+        let end = list.set_mark("end", 5);
+        list.push_front(-1);
+
+        for x in list.range_mut(start, end).unwrap() {
+            *x *= 10;
+        }
+        assert_eq!(list, [-1, 0, 1, 20, 30, 40, 5, 6, 7]);
+
+        list.drain(..4);
+        assert_eq!(list.range_mut(start, end).err(), Some(MarkError::Removed));
+    }
+
+    #[test]
+    fn test_slice_between_cursors() {
+        let list: LinkedList<i32> = (0..6).collect();
+        let start = list.lower_bound(&1);
+        let end = list.lower_bound(&4);
+        assert!(start.slice_to(&end).iter().eq(&[1, 2, 3]));
+        assert!(end.slice_to(&end).is_empty());
+
+        let ghost = list.lower_bound(&10);
+        assert!(end.slice_to(&ghost).iter().eq(&[4, 5]));
+    }
+}
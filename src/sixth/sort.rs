@@ -0,0 +1,916 @@
+use std::{cmp::Ordering, mem};
+
+use super::{node::NodePtr, observer::Event, LinkedList};
+
+// a chain of `len` nodes linked through their `next` pointers, starting at the first field
+type Run<T> = (NodePtr<T>, usize);
+
+// a merge in progress: the `merged.1` nodes taken so far, from `merged.0` to `tail`, followed
+// by what's left of `a` and `b`
+struct Merge<T> {
+    merged: Run<T>,
+    tail: NodePtr<T>,
+    a: Run<T>,
+    b: Run<T>,
+}
+
+// The state of a bottom-up merge sort: the nodes not reached yet, slot `i` of `pending` holding
+// a sorted run of 2^i nodes, the run being carried into them and the merge in progress. Only
+// `next` pointers are kept up to date while sorting, and every node is in exactly one of the
+// runs whenever `cmp` is called, so if it panics the runs are linked back into the list one
+// after another.
+struct MergeSort<'a, T> {
+    list: &'a mut LinkedList<T>,
+    rest: Run<T>,
+    pending: [Option<Run<T>>; usize::BITS as usize],
+    carry: Option<Run<T>>,
+    merge: Option<Merge<T>>,
+}
+
+impl<'a, T> MergeSort<'a, T> {
+    // merges two runs into one, taking from `a` on ties so that the merge is stable
+    unsafe fn merge_runs<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        a: Run<T>,
+        b: Run<T>,
+        cmp: &mut F,
+    ) -> Run<T> {
+        let len = a.1 + b.1;
+        let merge = self.merge.insert(Merge {
+            merged: (a.0, 0),
+            tail: a.0,
+            a,
+            b,
+        });
+
+        while merge.a.1 != 0 && merge.b.1 != 0 {
+            let run = if cmp(merge.b.0.get_unchecked(), merge.a.0.get_unchecked()) == Ordering::Less
+            {
+                &mut merge.b
+            } else {
+                &mut merge.a
+            };
+            let node = run.0;
+            *run = (node.next(), run.1 - 1);
+
+            if merge.merged.1 == 0 {
+                merge.merged.0 = node;
+            } else {
+                merge.tail.set_next(node);
+            }
+            merge.tail = node;
+            merge.merged.1 += 1;
+        }
+
+        // whatever is left of either run already follows in order
+        let rest = if merge.a.1 != 0 { merge.a.0 } else { merge.b.0 };
+        merge.tail.set_next(rest);
+
+        let head = merge.merged.0;
+        self.merge = None;
+        (head, len)
+    }
+
+    // links every run back into the ring, rebuilding the `prev` pointers
+    unsafe fn relink(&mut self) {
+        let merge = self
+            .merge
+            .as_ref()
+            .map(|merge| [merge.merged, merge.a, merge.b]);
+        let runs = self
+            .pending
+            .into_iter()
+            .flatten()
+            .chain(self.carry)
+            .chain(merge.into_iter().flatten())
+            .chain([self.rest]);
+
+        let dummy = self.list.dummy.unwrap_unchecked();
+        let mut prev = dummy;
+        for (mut node, len) in runs {
+            for _ in 0..len {
+                let next = node.next();
+                prev.link(node);
+                prev = node;
+                node = next;
+            }
+        }
+        prev.link(dummy);
+    }
+
+    fn finish(mut self) {
+        unsafe { self.relink() };
+        mem::forget(self);
+    }
+}
+
+impl<'a, T> Drop for MergeSort<'a, T> {
+    fn drop(&mut self) {
+        unsafe { self.relink() };
+        self.list.notify(Event::Reorder);
+    }
+}
+
+// A partition in progress: the nodes not compared yet still follow `before` in the list, and
+// the rest are in the buckets. Splicing the buckets back in after `before` puts every node
+// back into the list, which is how the partition ends and also how it's undone if `cmp` panics.
+struct Partition<'a, T> {
+    list: &'a mut LinkedList<T>,
+    before: NodePtr<T>,
+    buckets: &'a mut [LinkedList<T>; 3],
+}
+
+impl<'a, T> Partition<'a, T> {
+    unsafe fn rejoin(&mut self) {
+        let at = self.before.next();
+        for bucket in self.buckets.iter_mut() {
+            self.list.transfer_all(at, bucket);
+        }
+    }
+
+    fn finish(mut self) {
+        unsafe { self.rejoin() };
+        mem::forget(self);
+    }
+}
+
+impl<'a, T> Drop for Partition<'a, T> {
+    fn drop(&mut self) {
+        unsafe { self.rejoin() };
+        self.list.notify(Event::Reorder);
+    }
+}
+
+// Tells observers the list was reordered if an insertion sort moved any node, once it's done or
+// if `cmp` panics partway through.
+struct InsertionSort<'a, T> {
+    list: &'a mut LinkedList<T>,
+    moved: bool,
+}
+
+impl<'a, T> Drop for InsertionSort<'a, T> {
+    fn drop(&mut self) {
+        if self.moved {
+            self.list.notify(Event::Reorder);
+        }
+    }
+}
+
+// Tells both lists what a merge moved once it's done, or if `cmp` panics partway through: the
+// nodes taken so far came off the front of `other` and were spliced into `list`, which started
+// out with `index` elements, while `other` started out with `len`.
+struct MergeEvents<'a, T> {
+    list: &'a mut LinkedList<T>,
+    other: &'a mut LinkedList<T>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T> Drop for MergeEvents<'a, T> {
+    fn drop(&mut self) {
+        let moved = self.len - self.other.len;
+        if self.other.len == 0 {
+            self.other.notify(Event::Clear(moved));
+        } else {
+            self.other.notify(Event::Remove(0, moved));
+        }
+        self.list.notify(Event::Splice(self.index, moved));
+        if self.index != 0 && moved != 0 {
+            self.list.notify(Event::Reorder);
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    // Bottom-up merge sort over the `next` pointers. `prev` pointers are rebuilt in one final
+    // pass. If `cmp` panics, the elements are left in an unspecified order and observers are
+    // told the list was reordered.
+    pub(crate) fn merge_sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        let Some(dummy) = self.dummy else {
+            return;
+        };
+        let rest = (dummy.next(), self.len);
+        let mut sort = MergeSort {
+            list: self,
+            rest,
+            pending: [None; usize::BITS as usize],
+            carry: None,
+            merge: None,
+        };
+
+        while sort.rest.1 != 0 {
+            let node = sort.rest.0;
+            sort.rest = (node.next(), sort.rest.1 - 1);
+
+            let mut run = (node, 1);
+            let mut slot = 0;
+            while let Some(earlier) = sort.pending[slot].take() {
+                run = unsafe { sort.merge_runs(earlier, run, &mut cmp) };
+                slot += 1;
+            }
+            sort.pending[slot] = Some(run);
+        }
+
+        for slot in 0..sort.pending.len() {
+            let Some(earlier) = sort.pending[slot].take() else {
+                continue;
+            };
+            sort.carry = Some(match sort.carry.take() {
+                Some(later) => unsafe { sort.merge_runs(earlier, later, &mut cmp) },
+                None => earlier,
+            });
+        }
+
+        sort.finish();
+    }
+
+    // merges the sorted list `other` into the sorted list `self` by relinking, leaving `other`
+    // empty. On ties, elements of `self` come first.
+    pub(crate) fn merge_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: &mut LinkedList<T>,
+        mut cmp: F,
+    ) {
+        let dummy = self.init();
+        let mut at = dummy.next();
+
+        while let Some(front) = other.front_node() {
+            while at != dummy
+                && cmp(unsafe { front.get_unchecked() }, unsafe {
+                    at.get_unchecked()
+                }) != Ordering::Less
+            {
+                at = at.next();
+            }
+
+            let (back, len) = if at == dummy {
+                (other.back_node().unwrap(), other.len)
+            } else {
+                let mut back = front;
+                let mut len = 1;
+                while len < other.len
+                    && cmp(unsafe { back.next().get_unchecked() }, unsafe {
+                        at.get_unchecked()
+                    }) == Ordering::Less
+                {
+                    back = back.next();
+                    len += 1;
+                }
+                (back, len)
+            };
+
+            unsafe { self.transfer(at, other, front, back, len) };
+        }
+    }
+
+    // Partitions the `len` nodes after `before` around the middle one by moving them into
+    // less/equal/greater buckets and splicing the buckets back in place. Returns the bucket
+    // lengths and the last node of the equal bucket, after which the greater nodes start.
+    // `len` must be at least 1, and the buckets must be initialized and empty.
+    unsafe fn partition_segment<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        before: NodePtr<T>,
+        len: usize,
+        buckets: &mut [LinkedList<T>; 3],
+        cmp: &mut F,
+    ) -> ([usize; 3], NodePtr<T>) {
+        let pivot = (0..len / 2).fold(before.next(), |node, _| node.next());
+        let equal_dummy = buckets[1].init();
+        buckets[1].transfer(equal_dummy, self, pivot, pivot, 1);
+        let pivot = pivot.get_unchecked();
+
+        let partition = Partition {
+            list: self,
+            before,
+            buckets,
+        };
+        let mut node = before.next();
+        for _ in 1..len {
+            let next = node.next();
+            let bucket = match cmp(node.get_unchecked(), pivot) {
+                Ordering::Less => &mut partition.buckets[0],
+                Ordering::Equal => &mut partition.buckets[1],
+                Ordering::Greater => &mut partition.buckets[2],
+            };
+            let at = bucket.init();
+            bucket.transfer(at, partition.list, node, node, 1);
+            node = next;
+        }
+
+        let lens = partition.buckets.each_ref().map(LinkedList::len);
+        let equal_back = partition.buckets[1].back_node().unwrap();
+        partition.finish();
+
+        (lens, equal_back)
+    }
+
+    // sorts the `len` nodes after `before`, recursing into the smaller side of each partition
+    // and looping on the larger one so that the recursion stays O(log n) deep
+    unsafe fn quicksort_segment<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        mut before: NodePtr<T>,
+        mut len: usize,
+        buckets: &mut [LinkedList<T>; 3],
+        cmp: &mut F,
+    ) {
+        while len > 1 {
+            let (lens, equal_back) = self.partition_segment(before, len, buckets, cmp);
+            if lens[0] < lens[2] {
+                self.quicksort_segment(before, lens[0], buckets, cmp);
+                before = equal_back;
+                len = lens[2];
+            } else {
+                self.quicksort_segment(equal_back, lens[2], buckets, cmp);
+                len = lens[0];
+            }
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Stable bottom-up merge sort that relinks nodes rather than moving elements. Runs in
+    /// O(n log n) and allocates nothing.
+    ///
+    /// If `cmp` panics, the list keeps all of its elements, in an unspecified order.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, cmp: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        self.merge_sort_by(cmp);
+        self.notify(Event::Reorder);
+    }
+
+    /// Merges the sorted list `other` into this sorted list by relinking nodes, leaving `other`
+    /// empty. Runs in O(n + m) and allocates nothing. The merge is stable: elements of `self`
+    /// come before equal elements of `other`.
+    ///
+    /// If either list isn't sorted by `cmp`, the result is some interleaving of the two.
+    ///
+    /// If `cmp` panics, both lists keep their elements, with part of `other` already merged
+    /// into this list.
+    pub fn merge<F: FnMut(&T, &T) -> Ordering>(&mut self, other: &mut LinkedList<T>, cmp: F) {
+        let (index, len) = (self.len, other.len);
+        if len == 0 {
+            return;
+        }
+
+        let events = MergeEvents {
+            list: self,
+            other,
+            index,
+            len,
+        };
+        events.list.merge_by(events.other, cmp);
+    }
+
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp)
+    }
+
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Quicksort by relinking: each segment is partitioned around its middle element into
+    /// less/equal/greater sub-lists, which are spliced back in place, and the less and greater
+    /// parts are then sorted in turn. Elements equal to the pivot are never compared again, so
+    /// lists with many duplicates sort quickly. Expected O(n log n).
+    ///
+    /// If `cmp` panics, the list keeps all of its elements, in an unspecified order.
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        let dummy = self.init();
+        let mut buckets = [LinkedList::new(), LinkedList::new(), LinkedList::new()];
+        for bucket in &mut buckets {
+            bucket.init();
+        }
+
+        let len = self.len;
+        unsafe { self.quicksort_segment(dummy, len, &mut buckets, &mut cmp) };
+        self.notify(Event::Reorder);
+    }
+
+    pub fn sort_insertion(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_insertion_by(T::cmp)
+    }
+
+    /// Stable insertion sort that walks the list once and relinks each out-of-place node
+    /// backwards to its position. Runs in O(n + inversions), which makes it the better choice
+    /// for lists that are already nearly sorted.
+    ///
+    /// If `cmp` panics, the list keeps all of its elements, in an unspecified order.
+    pub fn sort_insertion_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        let (Some(dummy), Some(iter)) = (self.dummy, unsafe { self.raw_iter() }) else {
+            return;
+        };
+
+        let mut sort = InsertionSort {
+            list: self,
+            moved: false,
+        };
+        for node in iter.skip(1) {
+            let item = unsafe { node.get_unchecked() };
+
+            let mut at = node.prev();
+            while at != dummy && cmp(item, unsafe { at.get_unchecked() }) == Ordering::Less {
+                at = at.prev();
+            }
+
+            if at != node.prev() {
+                unsafe {
+                    NodePtr::unlink(node, node, 1, sort.list);
+                    at.splice_after(node, node, 1, sort.list);
+                }
+                sort.moved = true;
+            }
+        }
+    }
+
+    pub fn select_nth_unstable(&mut self, index: usize) -> &mut T
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable_by(index, T::cmp)
+    }
+
+    pub fn select_nth_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        index: usize,
+        mut f: F,
+    ) -> &mut T {
+        self.select_nth_unstable_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Reorders the list so that the element at `index` is where it would be if the list were
+    /// sorted, with every element before it comparing less or equal and every element after
+    /// it greater or equal. Returns that element.
+    ///
+    /// Quickselect by relinking: each round moves the nodes of the current segment into
+    /// less/equal/greater buckets around a pivot and splices the buckets back in place, then
+    /// continues in the bucket containing `index`. Expected O(n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn select_nth_unstable_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        index: usize,
+        mut cmp: F,
+    ) -> &mut T {
+        assert!(
+            index < self.len,
+            "index {} out of bounds for a list of length {}",
+            index,
+            self.len
+        );
+
+        let dummy = self.init();
+        let mut buckets = [LinkedList::new(), LinkedList::new(), LinkedList::new()];
+        for bucket in &mut buckets {
+            bucket.init();
+        }
+
+        // the segment still being searched is the `len` nodes after `before`
+        let (mut before, mut len, mut index) = (dummy, self.len, index);
+        while len > 1 {
+            let (lens, equal_back) =
+                unsafe { self.partition_segment(before, len, &mut buckets, &mut cmp) };
+
+            if index < lens[0] {
+                len = lens[0];
+            } else if index < lens[0] + lens[1] {
+                break;
+            } else {
+                before = equal_back;
+                index -= lens[0] + lens[1];
+                len = lens[2];
+            }
+        }
+
+        self.notify(Event::Reorder);
+
+        let node = (0..=index).fold(before, |node, _| node.next());
+        unsafe { node.get_mut_unchecked() }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList, MarkError,
+    };
+
+    #[test]
+    fn test_merge_sort() {
+        for len in 0..40 {
+            let items: Vec<i32> = (0..len).map(|i| (i * 7919) % 13).collect();
+            let mut list: LinkedList<i32> = items.iter().copied().collect();
+            list.merge_sort_by(i32::cmp);
+
+            let mut expected = items.clone();
+            expected.sort();
+            assert!(list.iter().eq(&expected));
+            assert!(list.iter().rev().eq(expected.iter().rev()));
+            assert_eq!(list.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_stable() {
+        let mut list: LinkedList<(i32, usize)> = (0..100).map(|i| (i % 5, i as usize)).collect();
+        list.merge_sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut expected = list.iter().copied().collect::<Vec<_>>();
+        expected.sort();
+        assert!(list.iter().eq(&expected));
+    }
+
+    #[test]
+    fn test_merge_sort_panic() {
+        let mut list: LinkedList<Box<i32>> = (0..20).rev().map(Box::new).collect();
+
+        let mut count = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.merge_sort_by(|a, b| {
+                count += 1;
+                if count == 30 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        list.validate();
+        let mut items: Vec<i32> = list.iter().map(|item| **item).collect();
+        items.sort();
+        assert!(items.into_iter().eq(0..20));
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list: LinkedList<i32> = [5, -3, 8, 0, 8, 1, -7].into_iter().collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.sort();
+        assert!(list.iter().eq(&[-7, -3, 0, 1, 5, 8, 8]));
+        assert!(list.iter().rev().eq(&[8, 8, 5, 1, 0, -3, -7]));
+        list.sort_by(|a, b| b.cmp(a));
+        assert!(list.iter().eq(&[8, 8, 5, 1, 0, -3, -7]));
+        list.push_front(2);
+        list.validate();
+        assert_eq!(
+            events.take(),
+            [Event::Reorder, Event::Reorder, Event::Insert(0)]
+        );
+
+        let mut single: LinkedList<i32> = (0..1).collect();
+        single.set_observer(events.clone());
+        single.sort();
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    fn test_sort_panic() {
+        let mut list: LinkedList<String> = (0..10).rev().map(|i| i.to_string()).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let eight = list.set_mark("eight", 8);
+        let end = list.set_mark("end", 10);
+
+        let mut count = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                count += 1;
+                if count == 12 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        list.validate();
+        let mut items: Vec<&str> = list.iter().map(String::as_str).collect();
+        items.sort();
+        assert_eq!(items, ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]);
+        assert_eq!(events.take(), [Event::Reorder]);
+        assert_eq!(list.mark_index(eight), Err(MarkError::Reordered));
+        assert_eq!(list.mark_index(end), Ok(10));
+    }
+
+    #[test]
+    fn test_sort_by_key_stable() {
+        let mut list: LinkedList<&str> = ["pear", "fig", "apple", "kiwi", "date", "plum", "yam"]
+            .into_iter()
+            .collect();
+        list.sort_by_key(|s| s.len());
+        assert!(list
+            .iter()
+            .eq(&["fig", "yam", "pear", "kiwi", "date", "plum", "apple"]));
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        for len in 0..60 {
+            let items: Vec<i32> = (0..len).map(|i| (i * 7919) % 17 - 8).collect();
+            let mut list: LinkedList<i32> = items.iter().copied().collect();
+            list.sort_unstable();
+
+            let mut expected = items.clone();
+            expected.sort();
+            assert!(list.iter().eq(&expected));
+            assert!(list.iter().rev().eq(expected.iter().rev()));
+            assert_eq!(list.len(), expected.len());
+            list.validate();
+        }
+
+        let mut sorted: LinkedList<i32> = (0..100).collect();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        assert!(sorted.iter().eq((0..100).rev().collect::<Vec<_>>().iter()));
+    }
+
+    #[test]
+    fn test_sort_unstable_by_key() {
+        let mut list: LinkedList<(i32, char)> = [(3, 'a'), (1, 'b'), (3, 'c'), (0, 'd'), (2, 'e')]
+            .into_iter()
+            .collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        list.sort_unstable_by_key(|item| item.0);
+        assert!(list.iter().map(|item| item.0).eq([0, 1, 2, 3, 3]));
+        assert_eq!(events.take(), [Event::Reorder]);
+    }
+
+    #[test]
+    fn test_sort_unstable_panic() {
+        let mut list: LinkedList<Box<i32>> = (0..20).map(|i| Box::new((i * 7) % 20)).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let inside = list.set_mark("inside", 10);
+        let end = list.set_mark("end", 20);
+
+        let mut count = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_unstable_by(|a, b| {
+                count += 1;
+                if count == 25 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        list.validate();
+        assert_eq!(events.take(), [Event::Reorder]);
+        assert_eq!(list.mark_index(inside), Err(MarkError::Reordered));
+        assert_eq!(list.mark_index(end), Ok(20));
+        let mut items: Vec<i32> = list.iter().map(|item| **item).collect();
+        items.sort_unstable();
+        assert!(items.into_iter().eq(0..20));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut evens: LinkedList<i32> = (0..10).step_by(2).collect();
+        let mut odds: LinkedList<i32> = (1..10).step_by(2).collect();
+        let events = Recorder::default();
+        evens.set_observer(events.clone());
+        let end = evens.set_mark("end", 5);
+
+        evens.merge(&mut odds, i32::cmp);
+        assert!(evens.iter().eq(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        assert!(evens.iter().rev().eq(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]));
+        assert!(odds.is_empty());
+        assert_eq!(evens.mark_index(end), Ok(10));
+        assert_eq!(events.take(), [Event::Splice(5, 5), Event::Reorder]);
+
+        evens.merge(&mut odds, i32::cmp);
+        assert!(events.take().is_empty());
+
+        let mut empty = LinkedList::new();
+        empty.set_observer(events.clone());
+        empty.merge(&mut evens, i32::cmp);
+        assert_eq!(empty.len(), 10);
+        assert!(evens.is_empty());
+        assert_eq!(events.take(), [Event::Clear(10), Event::Splice(0, 10)]);
+        empty.validate();
+    }
+
+    #[test]
+    fn test_merge_panic() {
+        let mut evens: LinkedList<i32> = (0..10).step_by(2).collect();
+        let mut odds: LinkedList<i32> = (1..10).step_by(2).collect();
+        let (evens_events, odds_events) = (Recorder::default(), Recorder::default());
+        evens.set_observer(evens_events.clone());
+        odds.set_observer(odds_events.clone());
+        let end = evens.set_mark("end", 5);
+        let nine = odds.set_mark("nine", 4);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            evens.merge(&mut odds, |a, b| {
+                if *a == 7 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+
+        // 1 and 3 had been merged when 7 was first compared
+        assert!(evens.iter().eq(&[0, 1, 2, 3, 4, 6, 8]));
+        assert!(odds.iter().eq(&[5, 7, 9]));
+        assert_eq!(evens_events.take(), [Event::Splice(5, 2), Event::Reorder]);
+        assert_eq!(odds_events.take(), [Event::Remove(0, 2)]);
+        assert_eq!(evens.mark_index(end), Ok(7));
+        assert_eq!(odds.mark_index(nine), Ok(2));
+        evens.validate();
+        odds.validate();
+    }
+
+    #[test]
+    fn test_sort_insertion() {
+        for len in 0..30 {
+            let items: Vec<i32> = (0..len).map(|i| (i * 7919) % 13).collect();
+            let mut list: LinkedList<i32> = items.iter().copied().collect();
+            list.sort_insertion();
+
+            let mut expected = items.clone();
+            expected.sort();
+            assert!(list.iter().eq(&expected));
+            assert!(list.iter().rev().eq(expected.iter().rev()));
+            assert_eq!(list.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn test_sort_insertion_panic() {
+        let mut list: LinkedList<i32> = [3, 1, 2, 5, 4].into_iter().collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+        let four = list.set_mark("four", 4);
+        let end = list.set_mark("end", 5);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_insertion_by(|a, b| {
+                if *a == 4 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+
+        // 1 and 2 had been moved in front of 3 when the comparison panicked
+        assert!(list.iter().eq(&[1, 2, 3, 5, 4]));
+        assert_eq!(events.take(), [Event::Reorder]);
+        assert_eq!(list.mark_index(four), Err(MarkError::Reordered));
+        assert_eq!(list.mark_index(end), Ok(5));
+        list.validate();
+
+        // nothing had moved yet
+        let mut sorted: LinkedList<i32> = (0..3).collect();
+        sorted.set_observer(events.clone());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            sorted.sort_insertion_by(|_, _| panic!("comparison failed"))
+        }));
+        assert!(result.is_err());
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    fn test_sort_insertion_nearly_sorted() {
+        let mut list: LinkedList<(i32, char)> = [
+            (1, 'a'),
+            (2, 'a'),
+            (4, 'a'),
+            (3, 'a'),
+            (5, 'a'),
+            (2, 'b'),
+            (6, 'a'),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut comparisons = 0;
+        list.sort_insertion_by(|a, b| {
+            comparisons += 1;
+            a.0.cmp(&b.0)
+        });
+
+        assert!(list.iter().eq(&[
+            (1, 'a'),
+            (2, 'a'),
+            (2, 'b'),
+            (3, 'a'),
+            (4, 'a'),
+            (5, 'a'),
+            (6, 'a')
+        ]));
+        assert!(comparisons <= 6 + 4);
+    }
+
+    #[test]
+    fn test_select_nth_unstable() {
+        for len in 1..30 {
+            let items: Vec<i32> = (0..len).map(|i| (i * 7919) % 11).collect();
+            let mut sorted = items.clone();
+            sorted.sort();
+
+            for index in 0..len as usize {
+                let mut list: LinkedList<i32> = items.iter().copied().collect();
+                let nth = *list.select_nth_unstable(index);
+                assert_eq!(nth, sorted[index]);
+
+                let after: Vec<i32> = list.iter().copied().collect();
+                assert_eq!(after[index], nth);
+                assert!(after[..index].iter().all(|x| *x <= nth));
+                assert!(after[index + 1..].iter().all(|x| *x >= nth));
+                assert!(list.iter().rev().eq(after.iter().rev()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_select_nth_unstable_by_key() {
+        let mut list: LinkedList<(i32, char)> = [(5, 'a'), (1, 'b'), (4, 'c'), (2, 'd')]
+            .into_iter()
+            .collect();
+
+        let median = list.select_nth_unstable_by_key(2, |item| item.0);
+        assert_eq!(*median, (4, 'c'));
+        median.1 = 'z';
+        assert!(list.iter().any(|item| *item == (4, 'z')));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_unstable_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.select_nth_unstable(3);
+    }
+
+    #[test]
+    fn test_merge_by() {
+        let mut a: LinkedList<(i32, char)> = [(1, 'a'), (3, 'a'), (3, 'a'), (9, 'a')]
+            .into_iter()
+            .collect();
+        let mut b: LinkedList<(i32, char)> = [(0, 'b'), (3, 'b'), (4, 'b'), (10, 'b'), (11, 'b')]
+            .into_iter()
+            .collect();
+
+        a.merge_by(&mut b, |x, y| x.0.cmp(&y.0));
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 9);
+        assert!(a.iter().eq(&[
+            (0, 'b'),
+            (1, 'a'),
+            (3, 'a'),
+            (3, 'a'),
+            (3, 'b'),
+            (4, 'b'),
+            (9, 'a'),
+            (10, 'b'),
+            (11, 'b')
+        ]));
+        assert!(a
+            .iter()
+            .rev()
+            .map(|x| x.0)
+            .eq([11, 10, 9, 4, 3, 3, 3, 1, 0]));
+
+        let mut empty = LinkedList::new();
+        empty.merge_by(&mut a, |x, y| x.0.cmp(&y.0));
+        assert_eq!(empty.len(), 9);
+        assert!(a.is_empty());
+    }
+}
@@ -0,0 +1,698 @@
+use std::{mem, sync::Arc};
+
+#[cfg(debug_assertions)]
+use super::debug::DebugInfo;
+use super::{
+    marks::Marks,
+    node::NodePtr,
+    observer::{BoxedObserver, Event},
+    pool::RawPool,
+    LinkedList,
+};
+
+impl<T> LinkedList<T> {
+    // detaches the first `len` elements, the last of which is `back`, as a new list
+    // `len` must be non-zero and `back` must be the node at index `len - 1`
+    pub(crate) unsafe fn detach_front(&mut self, back: NodePtr<T>, len: usize) -> LinkedList<T> {
+        let front = self.dummy.unwrap_unchecked().next();
+        let list = NodePtr::slice_off_as_list(front, back, len, self);
+        self.notify(Event::Remove(0, len));
+        list
+    }
+
+    // detaches the last `len` elements, the first of which is `front`, as a new list
+    // `len` must be non-zero and `front` must be the node at index `self.len - len`
+    pub(crate) unsafe fn detach_back(&mut self, front: NodePtr<T>, len: usize) -> LinkedList<T> {
+        let back = self.dummy.unwrap_unchecked().prev();
+        let list = NodePtr::slice_off_as_list(front, back, len, self);
+        self.notify(Event::Remove(self.len, len));
+        list
+    }
+
+    /// Detaches the elements from index `at` onward as a new list, by relinking, walking from
+    /// whichever end is closer to `at`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(
+            at <= self.len,
+            "cannot split off at index {} in a list of length {}",
+            at,
+            self.len
+        );
+
+        match self.walk_to(at, None) {
+            Some(front) if at != self.len => unsafe { self.detach_back(front, self.len - at) },
+            _ => self.new_sibling(),
+        }
+    }
+
+    /// Detaches the last `n` elements as a new list, walking backwards from the tail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    pub fn split_off_back(&mut self, n: usize) -> LinkedList<T> {
+        assert!(
+            n <= self.len,
+            "cannot split off {} elements from a list of length {}",
+            n,
+            self.len
+        );
+
+        match self.back_node() {
+            Some(back) if n != 0 => {
+                let front = (1..n).fold(back, |node, _| node.prev());
+                unsafe { self.detach_back(front, n) }
+            }
+            _ => self.new_sibling(),
+        }
+    }
+
+    /// Splits the list into `parts` contiguous lists whose lengths differ by at most one, with
+    /// the longer parts first. Some parts are empty if `parts > len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is zero.
+    pub fn split_n(mut self, parts: usize) -> Vec<LinkedList<T>> {
+        assert!(parts != 0, "cannot split a list into zero parts");
+
+        let (base, extra) = (self.len / parts, self.len % parts);
+        (0..parts)
+            .map(|part| {
+                let len = base + usize::from(part < extra);
+                match self.front_node() {
+                    Some(front) if len != 0 => {
+                        let back = (1..len).fold(front, |node, _| node.next());
+                        unsafe { self.detach_front(back, len) }
+                    }
+                    _ => self.new_sibling(),
+                }
+            })
+            .collect()
+    }
+
+    /// Removes the longest prefix of elements matching `pred` and returns it as a new list.
+    pub fn pop_front_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> LinkedList<T> {
+        let Some(iter) = (unsafe { self.raw_iter() }) else {
+            return self.new_sibling();
+        };
+
+        let prefix = iter
+            .take_while(|node| pred(unsafe { node.get_unchecked() }))
+            .enumerate()
+            .last();
+        match prefix {
+            Some((index, back)) => unsafe { self.detach_front(back, index + 1) },
+            None => self.new_sibling(),
+        }
+    }
+
+    /// Removes the longest suffix of elements matching `pred` and returns it as a new list.
+    pub fn pop_back_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> LinkedList<T> {
+        let Some(iter) = (unsafe { self.raw_iter() }) else {
+            return self.new_sibling();
+        };
+
+        let suffix = iter
+            .rev()
+            .take_while(|node| pred(unsafe { node.get_unchecked() }))
+            .enumerate()
+            .last();
+        match suffix {
+            Some((index, front)) => unsafe { self.detach_back(front, index + 1) },
+            None => self.new_sibling(),
+        }
+    }
+
+    /// Removes up to `n` elements from the front and returns them as a new list. The nodes are
+    /// detached together, so past the walk to the `n`th element this takes constant time.
+    pub fn pop_front_n(&mut self, n: usize) -> LinkedList<T> {
+        let n = n.min(self.len);
+        match self.front_node() {
+            Some(front) if n != 0 => {
+                let back = (1..n).fold(front, |node, _| node.next());
+                unsafe { self.detach_front(back, n) }
+            }
+            _ => self.new_sibling(),
+        }
+    }
+
+    /// Removes up to `n` elements from the back and returns them as a new list, like
+    /// [`LinkedList::pop_front_n`].
+    pub fn pop_back_n(&mut self, n: usize) -> LinkedList<T> {
+        self.split_off_back(n.min(self.len))
+    }
+
+    /// Finds the first element matching `pred` and detaches it and everything after it as a
+    /// new list, in a single pass. Returns `None`, leaving the list as it was, if no element
+    /// matches.
+    pub fn split_when<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<LinkedList<T>> {
+        let (index, front) = unsafe { self.raw_iter() }?
+            .enumerate()
+            .find(|(_, node)| pred(unsafe { node.get_unchecked() }))?;
+        let len = self.len - index;
+        Some(unsafe { self.detach_back(front, len) })
+    }
+
+    /// Returns an iterator over the maximal runs of adjacent elements for which `pred` returns
+    /// true on every neighbouring pair, like `slice::chunk_by`. Each run is detached as its
+    /// own list by relinking, so nothing is copied or allocated apart from the runs' dummy
+    /// nodes.
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(self, pred: F) -> ChunkBy<T, F> {
+        ChunkBy { list: self, pred }
+    }
+
+    /// Returns an iterator that detaches successive lists of `size` elements from the front,
+    /// by relinking, with a shorter last list if `size` doesn't divide the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(self, size: usize) -> Chunks<T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { list: self, size }
+    }
+
+    pub fn split_inclusive<F: FnMut(&T) -> bool>(self, pred: F) -> SplitInclusive<T, F> {
+        SplitInclusive { list: self, pred }
+    }
+
+    /// Splits the list into `n` contiguous sub-lists of balanced length, like [`split_n`], that
+    /// can be sent to other threads independently and later joined back up in their original
+    /// order with [`Parts::rejoin`].
+    ///
+    /// The list's observer, if any, sees the list being cleared, is held on to by the parts,
+    /// and sees their contents spliced back in when they're rejoined. So do its marks. The
+    /// rejoined list also keeps the original's pool, debug name and debug ID.
+    ///
+    /// [`split_n`]: LinkedList::split_n
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split_for_parallel(mut self, n: usize) -> Parts<T> {
+        assert!(n != 0, "cannot split a list into zero parts");
+
+        let len = self.len;
+        self.notify(Event::Clear(len));
+        let observer = self.observer.take();
+        let marks = mem::take(&mut self.marks);
+        let pool = self.pool.clone();
+        #[cfg(debug_assertions)]
+        let debug = mem::replace(&mut self.debug, DebugInfo::new());
+
+        Parts {
+            parts: self.split_n(n),
+            observer,
+            marks,
+            pool,
+            #[cfg(debug_assertions)]
+            debug,
+        }
+    }
+}
+
+/// The sub-lists of a list split up by [`LinkedList::split_for_parallel`].
+pub struct Parts<T> {
+    parts: Vec<LinkedList<T>>,
+    observer: Option<BoxedObserver>,
+    marks: Marks,
+    pool: Option<Arc<RawPool>>,
+    #[cfg(debug_assertions)]
+    debug: DebugInfo,
+}
+
+impl<T> Parts<T> {
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[LinkedList<T>] {
+        &self.parts
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [LinkedList<T>] {
+        &mut self.parts
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, LinkedList<T>> {
+        self.parts.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, LinkedList<T>> {
+        self.parts.iter_mut()
+    }
+
+    /// Splices the parts back together in order, by relinking, into a single list.
+    pub fn rejoin(self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        list.pool = self.pool;
+        for mut part in self.parts {
+            list.splice_back(&mut part);
+        }
+
+        list.observer = self.observer;
+        list.marks = self.marks;
+        #[cfg(debug_assertions)]
+        {
+            list.debug = self.debug;
+        }
+        let len = list.len;
+        list.notify(Event::Splice(0, len));
+        list
+    }
+}
+
+// the observer isn't `Sync`, but it's only reachable by value, through `Parts::rejoin`
+unsafe impl<T: Sync> Sync for Parts<T> {}
+
+pub struct SplitInclusive<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for SplitInclusive<T, F> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dummy = self.list.dummy?;
+
+        let mut node = dummy.next();
+        let mut len = 0;
+        while node != dummy {
+            len += 1;
+            if (self.pred)(unsafe { node.get_unchecked() }) {
+                break;
+            }
+            node = node.next();
+        }
+
+        if len == 0 {
+            return None;
+        }
+
+        let back = if node == dummy { dummy.prev() } else { node };
+        Some(unsafe { self.list.detach_front(back, len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len.min(1), Some(len))
+    }
+}
+
+pub struct Chunks<T> {
+    list: LinkedList<T>,
+    size: usize,
+}
+
+impl<T> Iterator for Chunks<T> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.size.min(self.list.len);
+        let back = self.list.walk_to(len.checked_sub(1)?, None)?;
+        Some(unsafe { self.list.detach_front(back, len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len.div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Chunks<T> {
+    // the last chunk is the short one, as with `slice::chunks`
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = match self.list.len % self.size {
+            0 => self.size.min(self.list.len),
+            rest => rest,
+        };
+        if len == 0 {
+            return None;
+        }
+        let front = self.list.walk_to(self.list.len - len, None)?;
+        Some(unsafe { self.list.detach_back(front, len) })
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<T> {}
+
+pub struct ChunkBy<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<T, F> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dummy = self.list.dummy?;
+        let mut back = dummy.next();
+        if back == dummy {
+            return None;
+        }
+
+        let mut len = 1;
+        while back.next() != dummy
+            && (self.pred)(unsafe { back.get_unchecked() }, unsafe {
+                back.next().get_unchecked()
+            })
+        {
+            back = back.next();
+            len += 1;
+        }
+
+        Some(unsafe { self.list.detach_front(back, len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len.min(1), Some(len))
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> DoubleEndedIterator for ChunkBy<T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let dummy = self.list.dummy?;
+        let mut front = dummy.prev();
+        if front == dummy {
+            return None;
+        }
+
+        let mut len = 1;
+        while front.prev() != dummy
+            && (self.pred)(unsafe { front.prev().get_unchecked() }, unsafe {
+                front.get_unchecked()
+            })
+        {
+            front = front.prev();
+            len += 1;
+        }
+
+        Some(unsafe { self.list.detach_back(front, len) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use crate::sixth::{
+        observer::{test::Recorder, Event},
+        LinkedList, NodePool,
+    };
+
+    fn segments(list: LinkedList<i32>) -> Vec<Vec<i32>> {
+        list.split_inclusive(|x| *x == 0)
+            .map(|segment| segment.into_iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_inclusive() {
+        let list: LinkedList<i32> = [1, 2, 0, 3, 0, 4].into_iter().collect();
+        assert_eq!(segments(list), [vec![1, 2, 0], vec![3, 0], vec![4]]);
+
+        let list: LinkedList<i32> = [0, 0, 5, 0].into_iter().collect();
+        assert_eq!(segments(list), [vec![0], vec![0], vec![5, 0]]);
+
+        let list: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        assert_eq!(segments(list), [vec![1, 2, 3]]);
+
+        assert!(segments(LinkedList::new()).is_empty());
+    }
+
+    #[test]
+    fn test_chunks() {
+        let list: LinkedList<i32> = (0..7).collect();
+        let mut chunks = list.chunks(3);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.next().unwrap(), [0, 1, 2]);
+        assert_eq!(chunks.next_back().unwrap(), [6]);
+        assert_eq!(chunks.len(), 1);
+        let last = chunks.next_back().unwrap();
+        assert_eq!(last, [3, 4, 5]);
+        assert!(last.iter().rev().eq(&[5, 4, 3]));
+        assert!(chunks.next().is_none());
+        assert!(chunks.next_back().is_none());
+
+        let list: LinkedList<i32> = (0..4).collect();
+        let chunks: Vec<LinkedList<i32>> = list.chunks(2).rev().collect();
+        assert_eq!(chunks, [LinkedList::from([2, 3]), LinkedList::from([0, 1])]);
+        assert_eq!(LinkedList::<i32>::new().chunks(5).count(), 0);
+        assert_eq!(
+            LinkedList::from([1, 2]).chunks(5).collect::<Vec<_>>(),
+            [LinkedList::from([1, 2])]
+        );
+    }
+
+    #[test]
+    #[should_panic = "chunk size must be non-zero"]
+    fn test_chunks_zero() {
+        LinkedList::from([1]).chunks(0);
+    }
+
+    #[test]
+    fn test_chunk_by() {
+        let list: LinkedList<i32> = [1, 1, 2, 3, 3, 3, 1].into_iter().collect();
+        let chunks: Vec<LinkedList<i32>> = list.chunk_by(|a, b| a == b).collect();
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks[0], [1, 1]);
+        assert_eq!(chunks[1], [2]);
+        assert_eq!(chunks[2], [3, 3, 3]);
+        assert_eq!(chunks[3], [1]);
+        assert!(chunks[2].iter().rev().eq(&[3, 3, 3]));
+
+        // runs of ascending elements, from both ends
+        let list: LinkedList<i32> = [1, 2, 5, 0, 4, 3, 7, 8].into_iter().collect();
+        let mut runs = list.chunk_by(|a, b| a < b);
+        assert_eq!(runs.next().unwrap(), [1, 2, 5]);
+        assert_eq!(runs.next_back().unwrap(), [3, 7, 8]);
+        assert_eq!(runs.next_back().unwrap(), [0, 4]);
+        assert!(runs.next().is_none());
+        assert!(runs.next_back().is_none());
+
+        assert!(LinkedList::<i32>::new()
+            .chunk_by(|_, _| true)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let tail = list.split_off(4);
+        assert!(tail.iter().eq(&[4, 5]));
+        assert!(list.iter().eq(&[0, 1, 2, 3]));
+        assert_eq!(events.take(), [Event::Remove(4, 2)]);
+
+        assert!(list.split_off(4).is_empty());
+        let rest = list.split_off(1);
+        assert!(rest.iter().rev().eq(&[3, 2, 1]));
+        assert!(list.iter().eq(&[0]));
+
+        let all = list.split_off(0);
+        assert!(all.iter().eq(&[0]));
+        assert!(list.is_empty());
+        list.validate();
+        assert!(LinkedList::<i32>::new().split_off(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic = "cannot split off at index 4 in a list of length 3"]
+    fn test_split_off_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_split_off_back() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+
+        let tail = list.split_off_back(2);
+        assert!(tail.iter().eq(&[4, 5]));
+        assert!(list.iter().eq(&[0, 1, 2, 3]));
+
+        assert!(list.split_off_back(0).is_empty());
+        assert_eq!(list.len(), 4);
+
+        let all = list.split_off_back(4);
+        assert!(all.iter().rev().eq(&[3, 2, 1, 0]));
+        assert!(list.is_empty());
+        assert!(list.split_off_back(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_off_back_too_long() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.split_off_back(4);
+    }
+
+    #[test]
+    fn test_pop_n() {
+        let mut list: LinkedList<i32> = (0..8).collect();
+        let events = Recorder::default();
+        list.set_observer(events.clone());
+
+        let front = list.pop_front_n(3);
+        assert!(front.iter().eq(&[0, 1, 2]));
+        assert!(front.iter().rev().eq(&[2, 1, 0]));
+        let back = list.pop_back_n(2);
+        assert!(back.iter().eq(&[6, 7]));
+        assert!(list.iter().eq(&[3, 4, 5]));
+        assert_eq!(events.take(), [Event::Remove(0, 3), Event::Remove(3, 2)]);
+
+        assert!(list.pop_front_n(0).is_empty());
+        assert!(list.pop_back_n(0).is_empty());
+        assert!(list.pop_back_n(10).iter().eq(&[3, 4, 5]));
+        assert!(list.pop_front_n(1).is_empty());
+        assert!(list.is_empty());
+        list.validate();
+        front.validate();
+    }
+
+    #[test]
+    fn test_split_n() {
+        let parts = (0..10).collect::<LinkedList<i32>>().split_n(3);
+        assert_eq!(parts.len(), 3);
+        assert!(parts[0].iter().eq(&[0, 1, 2, 3]));
+        assert!(parts[1].iter().eq(&[4, 5, 6]));
+        assert!(parts[2].iter().rev().eq(&[9, 8, 7]));
+
+        let parts = (0..2).collect::<LinkedList<i32>>().split_n(4);
+        let lens = parts.iter().map(LinkedList::len).collect::<Vec<_>>();
+        assert_eq!(lens, [1, 1, 0, 0]);
+
+        let parts = LinkedList::<i32>::new().split_n(1);
+        assert_eq!(parts.len(), 1);
+        assert!(parts[0].is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_n_zero() {
+        (0..3).collect::<LinkedList<i32>>().split_n(0);
+    }
+
+    #[test]
+    fn test_split_for_parallel() {
+        let pool = NodePool::new();
+        let mut list = LinkedList::new_in_pool(&pool);
+        list.extend(0..1000u64);
+        list.set_debug_name("jobs");
+        let id = list.debug_id();
+        let recorder = Recorder::default();
+        list.set_observer(recorder.clone());
+
+        let mut parts = list.split_for_parallel(4);
+        assert_eq!(parts.len(), 4);
+        assert!(parts.iter().all(|part| part.len() == 250));
+        assert_eq!(recorder.take(), [Event::Clear(1000)]);
+
+        thread::scope(|scope| {
+            for part in parts.iter_mut() {
+                scope.spawn(move || {
+                    part.iter_mut().for_each(|x| *x *= 2);
+                    part.pop_front();
+                });
+            }
+        });
+
+        let list = parts.rejoin();
+        assert_eq!(list.len(), 996);
+        assert!(list
+            .iter()
+            .copied()
+            .eq((0..1000).filter(|x| x % 250 != 0).map(|x| x * 2)));
+        assert!(list.has_observer());
+        assert_eq!(recorder.take(), [Event::Splice(0, 996)]);
+        assert!(list.pool.is_some());
+        assert_eq!(list.debug_id(), id);
+        if let Some(id) = id {
+            assert_eq!(list.label().to_string(), format!("list #{} \"jobs\"", id));
+        }
+    }
+
+    #[test]
+    fn test_split_for_parallel_short() {
+        let mut parts = (0..2).collect::<LinkedList<i32>>().split_for_parallel(3);
+        assert!(parts.as_slice()[2].is_empty());
+        parts.as_mut_slice()[2].push_back(2);
+        assert!(parts.rejoin().iter().eq(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_pop_while() {
+        let mut list: LinkedList<i32> = [1, 2, 3, 10, 4, 20, 30].into_iter().collect();
+
+        let prefix = list.pop_front_while(|x| *x < 10);
+        assert!(prefix.iter().eq(&[1, 2, 3]));
+        assert!(list.iter().eq(&[10, 4, 20, 30]));
+
+        let suffix = list.pop_back_while(|x| *x >= 10);
+        assert!(suffix.iter().eq(&[20, 30]));
+        assert!(list.iter().eq(&[10, 4]));
+
+        assert!(list.pop_front_while(|x| *x < 0).is_empty());
+        assert!(list.pop_back_while(|x| *x < 0).is_empty());
+        assert_eq!(list.len(), 2);
+
+        let all = list.pop_front_while(|_| true);
+        assert!(all.iter().rev().eq(&[4, 10]));
+        assert!(list.is_empty());
+        assert!(list.pop_back_while(|_| true).is_empty());
+
+        list.push_back(1);
+        assert!(list.iter().eq(&[1]));
+    }
+
+    #[test]
+    fn test_split_when() {
+        let mut list: LinkedList<i32> = [1, 2, 3, 10, 4, 20].into_iter().collect();
+
+        let tail = list.split_when(|x| *x >= 10).unwrap();
+        assert_eq!(tail, [10, 4, 20]);
+        assert_eq!(list, [1, 2, 3]);
+        assert!(tail.iter().rev().eq(&[20, 4, 10]));
+
+        assert!(list.split_when(|x| *x < 0).is_none());
+        assert_eq!(list.len(), 3);
+        let all = list.split_when(|_| true).unwrap();
+        assert_eq!(all, [1, 2, 3]);
+        assert!(list.is_empty());
+        assert!(list.split_when(|_| true).is_none());
+
+        list.push_back(5);
+        list.validate();
+    }
+
+    #[test]
+    fn test_split_inclusive_segments_are_usable() {
+        let list: LinkedList<i32> = [1, 0, 2, 3, 0].into_iter().collect();
+        let mut iter = list.split_inclusive(|x| *x == 0);
+
+        let mut first = iter.next().unwrap();
+        assert_eq!(first.len(), 2);
+        first.push_back(9);
+        assert_eq!(first.pop_front(), Some(1));
+        assert!(first.iter().eq(&[0, 9]));
+
+        let second = iter.next().unwrap();
+        assert_eq!(second.len(), 3);
+        assert!(second.iter().rev().eq(&[0, 3, 2]));
+        assert!(iter.next().is_none());
+    }
+}
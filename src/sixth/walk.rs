@@ -0,0 +1,188 @@
+use std::{marker::PhantomData, ops::ControlFlow};
+
+use super::{iter::RawIter, observer::Event, LinkedList};
+
+/// What [`LinkedList::walk_mut`] should do with the element it just visited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Visit<B = ()> {
+    /// Keep the element and move on to the next one.
+    Keep,
+    /// Remove and drop the element, then move on to the next one.
+    Remove,
+    /// Keep the element and stop walking, returning the value.
+    Break(B),
+}
+
+impl<T> LinkedList<T> {
+    /// Visits the elements from front to back until `f` breaks.
+    pub fn walk<B, F: FnMut(&T) -> ControlFlow<B>>(&self, f: F) -> ControlFlow<B> {
+        self.iter().try_for_each(f)
+    }
+
+    /// Visits the elements from front to back, letting `f` modify each one and decide whether
+    /// to keep it, remove it or stop walking altogether.
+    pub fn walk_mut<B, F: FnMut(&mut T) -> Visit<B>>(&mut self, mut f: F) -> ControlFlow<B> {
+        let Some(iter) = (unsafe { self.raw_iter() }) else {
+            return ControlFlow::Continue(());
+        };
+
+        let mut kept = 0;
+        for node in iter {
+            match f(unsafe { node.get_mut_unchecked() }) {
+                Visit::Keep => kept += 1,
+                Visit::Remove => {
+                    unsafe { node.pop_unchecked(self) };
+                    self.notify(Event::Remove(kept, 1));
+                }
+                Visit::Break(value) => return ControlFlow::Break(value),
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// An iterator over each element paired with the one after it, created by
+/// [`LinkedList::iter_pairs`].
+pub struct Pairs<'a, T> {
+    // the first element of every pair
+    inner: Option<RawIter<T>>,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<T> LinkedList<T> {
+    /// Returns an iterator over each element and the one after it, front to back: `len - 1`
+    /// pairs, or none for fewer than two elements.
+    pub fn iter_pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            inner: self.raw_slice(..self.len.saturating_sub(1)),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Calls `f` with mutable references to each element and the one after it, front to back.
+    /// Each element is passed as the second of one pair and then as the first of the next, so
+    /// changes made to it as the second are seen when it comes round as the first.
+    pub fn for_each_pair_mut<F: FnMut(&mut T, &mut T)>(&mut self, mut f: F) {
+        if let Some(iter) = self.raw_slice(..self.len.saturating_sub(1)) {
+            for node in iter {
+                // Safety: a node and its successor are distinct elements
+                unsafe { f(node.get_mut_unchecked(), node.next().get_mut_unchecked()) };
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.inner.as_mut()?.next()?;
+        Some(unsafe { (node.get_unchecked(), node.next().get_unchecked()) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.map_or(0, |inner| inner.len());
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Pairs<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = self.inner.as_mut()?.next_back()?;
+        Some(unsafe { (node.get_unchecked(), node.next().get_unchecked()) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Pairs<'a, T> {}
+
+unsafe impl<'a, T: Sync> Send for Pairs<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Pairs<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use std::ops::ControlFlow;
+
+    use super::Visit;
+    use crate::sixth::LinkedList;
+
+    #[test]
+    fn test_walk() {
+        let list: LinkedList<i32> = (1..10).collect();
+
+        let mut sum = 0;
+        let found = list.walk(|x| {
+            sum += x;
+            if sum > 10 {
+                ControlFlow::Break(*x)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(found, ControlFlow::Break(5));
+        assert_eq!(sum, 15);
+
+        let mut count = 0;
+        let done = list.walk(|_| -> ControlFlow<()> {
+            count += 1;
+            ControlFlow::Continue(())
+        });
+        assert_eq!(done, ControlFlow::Continue(()));
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn test_walk_mut() {
+        let mut list: LinkedList<i32> = (0..10).collect();
+
+        let result = list.walk_mut(|x| match *x {
+            x if x % 3 == 0 => Visit::Remove,
+            7 => Visit::Break("stopped at 7"),
+            _ => {
+                *x *= 10;
+                Visit::Keep
+            }
+        });
+        assert_eq!(result, ControlFlow::Break("stopped at 7"));
+        assert!(list.iter().eq(&[10, 20, 40, 50, 7, 8, 9]));
+        assert!(list.iter().rev().eq(&[9, 8, 7, 50, 40, 20, 10]));
+
+        let result = list.walk_mut(|_| Visit::<()>::Remove);
+        assert_eq!(result, ControlFlow::Continue(()));
+        assert!(list.is_empty());
+        assert_eq!(
+            list.walk_mut(|_| Visit::Break(())),
+            ControlFlow::Continue(())
+        );
+    }
+
+    #[test]
+    fn test_iter_pairs() {
+        let list: LinkedList<i32> = [1, 4, 9, 16].into_iter().collect();
+        let deltas: Vec<i32> = list.iter_pairs().map(|(a, b)| b - a).collect();
+        assert_eq!(deltas, [3, 5, 7]);
+        assert_eq!(list.iter_pairs().len(), 3);
+        assert_eq!(list.iter_pairs().next_back(), Some((&9, &16)));
+
+        assert_eq!(LinkedList::from([1]).iter_pairs().next(), None);
+        assert_eq!(LinkedList::<i32>::new().iter_pairs().len(), 0);
+    }
+
+    #[test]
+    fn test_for_each_pair_mut() {
+        // running sums, since each update is seen by the next pair
+        let mut list: LinkedList<i32> = [1, 2, 3, 4].into_iter().collect();
+        list.for_each_pair_mut(|a, b| *b += *a);
+        assert_eq!(list, [1, 3, 6, 10]);
+
+        let mut list: LinkedList<i32> = [3, 1, 2].into_iter().collect();
+        list.for_each_pair_mut(|a, b| {
+            if a > b {
+                std::mem::swap(a, b)
+            }
+        });
+        assert_eq!(list, [1, 2, 3]);
+
+        LinkedList::<i32>::new().for_each_pair_mut(|_, _| panic!("no pairs"));
+        LinkedList::from([0]).for_each_pair_mut(|_, _| panic!("no pairs"));
+    }
+}
@@ -0,0 +1,206 @@
+use super::{
+    node::{Node, NodePtr},
+    LinkedList,
+};
+
+impl<T> LinkedList<T> {
+    /// Walks `self` and `other` in lockstep, calling `f` with each element of `self` and the
+    /// element of `other` at the same position.
+    ///
+    /// Stops at the end of the shorter list: surplus elements of `self` are left untouched and
+    /// surplus elements of `other` are dropped.
+    pub fn zip_with<U, F: FnMut(&mut T, U)>(&mut self, other: LinkedList<U>, mut f: F) {
+        for (item, other) in self.iter_mut().zip(other) {
+            f(item, other);
+        }
+    }
+}
+
+impl<A, B> LinkedList<(A, B)> {
+    /// Pairs up the elements of `a` and `b`, stopping at the end of the shorter list and dropping
+    /// the surplus elements of the other.
+    ///
+    /// When `Node<A>` or `Node<B>` has the same layout as `Node<(A, B)>`, the pairs are written
+    /// into the nodes of that list, like [`LinkedList::map`] does, so only the other list's
+    /// nodes are freed. Otherwise the pairs get new nodes.
+    pub fn zip(mut a: LinkedList<A>, mut b: LinkedList<B>) -> Self {
+        if Node::<A>::same_layout::<(A, B)>() {
+            unsafe { reuse_zipped(&mut a, || b.pop_front(), |a, b| (a, b)) }
+        } else if Node::<B>::same_layout::<(A, B)>() {
+            unsafe { reuse_zipped(&mut b, || a.pop_front(), |b, a| (a, b)) }
+        } else {
+            a.into_iter().zip(b).collect()
+        }
+    }
+
+    /// Splits a list of pairs into a list of first halves and a list of second halves, in one
+    /// pass that moves each half out of its pair as it goes.
+    ///
+    /// When `Node<A>` or `Node<B>` has the same layout as `Node<(A, B)>`, that half is written
+    /// back into the pair's node and only the other half gets a new one. Otherwise each pair's
+    /// node is freed.
+    pub fn unzip(mut self) -> (LinkedList<A>, LinkedList<B>) {
+        if Node::<(A, B)>::same_layout::<A>() {
+            let (left, right) = unsafe { self.reuse_unzipped(|(a, b)| (a, b)) };
+            (left, right)
+        } else if Node::<(A, B)>::same_layout::<B>() {
+            let (right, left) = unsafe { self.reuse_unzipped(|(a, b)| (b, a)) };
+            (left, right)
+        } else {
+            let mut left = self.new_sibling_of();
+            let mut right = self.new_sibling_of();
+            for (a, b) in self {
+                left.push_back(a);
+                right.push_back(b);
+            }
+            (left, right)
+        }
+    }
+
+    // Moves the pairs out of their nodes, splits each one with `split`, writes the first part
+    // back into the node and pushes the second onto a new list. `Node<(A, B)>` must have the
+    // same layout as `Node<K>`.
+    unsafe fn reuse_unzipped<K, R>(
+        &mut self,
+        split: impl Fn((A, B)) -> (K, R),
+    ) -> (LinkedList<K>, LinkedList<R>) {
+        let mut kept = self.new_sibling_of();
+        let mut rest = self.new_sibling_of();
+        let dummy = kept.init();
+
+        while let Some(node) = self.front_node() {
+            NodePtr::unlink(node, node, 1, self);
+            let (item, other) = split(node.get_raw_unchecked().as_ptr().read());
+
+            let node = node.cast::<K>();
+            node.get_raw_unchecked().as_ptr().write(item);
+            dummy.splice_before(node, node, 1, &mut kept);
+            rest.push_back(other);
+        }
+
+        (kept, rest)
+    }
+}
+
+// Pairs each element of `list` with the next item from `other`, writing the pair back into the
+// element's node, until either runs out. `Node<T>` must have the same layout as `Node<P>`.
+unsafe fn reuse_zipped<T, U, P>(
+    list: &mut LinkedList<T>,
+    mut other: impl FnMut() -> Option<U>,
+    pair: impl Fn(T, U) -> P,
+) -> LinkedList<P> {
+    let mut res = list.new_sibling_of();
+    let dummy = res.init();
+
+    while let Some(node) = list.front_node() {
+        let Some(other) = other() else {
+            break;
+        };
+        NodePtr::unlink(node, node, 1, list);
+        let item = node.get_raw_unchecked().as_ptr().read();
+
+        let node = node.cast::<P>();
+        node.get_raw_unchecked().as_ptr().write(pair(item, other));
+        dummy.splice_before(node, node, 1, &mut res);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::sixth::LinkedList;
+
+    #[test]
+    fn test_zip_with() {
+        let mut sums: LinkedList<i32> = (0..4).collect();
+        sums.zip_with((10..14).collect(), |a, b| *a += b);
+        assert!(sums.iter().eq(&[10, 12, 14, 16]));
+
+        let mut names: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        names.zip_with(["x", "y"].into_iter().collect(), |a, b| a.push_str(b));
+        assert!(names.iter().eq(&["ax", "by"]));
+    }
+
+    #[test]
+    fn test_zip_unzip() {
+        let numbers: LinkedList<i32> = (0..4).collect();
+        let letters: LinkedList<char> = "abc".chars().collect();
+
+        let pairs = LinkedList::zip(numbers, letters);
+        assert!(pairs.iter().eq(&[(0, 'a'), (1, 'b'), (2, 'c')]));
+
+        let (numbers, letters) = pairs.unzip();
+        assert!(numbers.iter().eq(&[0, 1, 2]));
+        assert!(letters.iter().rev().eq(&['c', 'b', 'a']));
+
+        let (left, right) = LinkedList::<(i32, i32)>::new().unzip();
+        assert!(left.is_empty() && right.is_empty());
+    }
+
+    #[test]
+    fn test_zip_unzip_same_layout() {
+        // a `Node<(u64, ())>` is laid out like a `Node<u64>`, so the pairs take over the nodes
+        // of `numbers` and the numbers take them back
+        let numbers: LinkedList<u64> = (0..4).collect();
+        let front = numbers.iter().next().map(|x| x as *const u64 as usize);
+
+        let pairs = LinkedList::zip(numbers, LinkedList::from([(), (), ()]));
+        assert!(pairs.iter().eq(&[(0, ()), (1, ()), (2, ())]));
+        assert_eq!(pairs.iter().next().map(|x| x as *const _ as usize), front);
+        pairs.validate();
+
+        let (numbers, units) = pairs.unzip();
+        assert!(numbers.iter().eq(&[0, 1, 2]));
+        assert_eq!(units.len(), 3);
+        assert_eq!(
+            numbers.iter().next().map(|x| x as *const u64 as usize),
+            front
+        );
+        numbers.validate();
+
+        // the second list's nodes are reused when only they fit
+        let units: LinkedList<()> = LinkedList::from([(), ()]);
+        let values: LinkedList<u64> = (5..8).collect();
+        let front = values.iter().next().map(|x| x as *const u64 as usize);
+        let pairs = LinkedList::zip(units, values);
+        assert!(pairs.iter().eq(&[((), 5), ((), 6)]));
+        assert_eq!(pairs.iter().next().map(|x| x as *const _ as usize), front);
+        let (units, values) = pairs.unzip();
+        assert_eq!(units.len(), 2);
+        assert!(values.iter().eq(&[5, 6]));
+        assert_eq!(
+            values.iter().next().map(|x| x as *const u64 as usize),
+            front
+        );
+    }
+
+    #[test]
+    fn test_unzip_moves() {
+        let shared = Rc::new(0);
+        let pairs: LinkedList<(Rc<i32>, String)> =
+            (0..3).map(|i| (shared.clone(), i.to_string())).collect();
+
+        let (rcs, strings) = pairs.unzip();
+        assert_eq!(Rc::strong_count(&shared), 4);
+        assert_eq!(strings, ["0", "1", "2"]);
+        drop(rcs);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+
+    #[test]
+    fn test_zip_with_unequal_lengths() {
+        let mut longer: LinkedList<i32> = (0..5).collect();
+        longer.zip_with((0..2).collect(), |a, b| *a -= b + 1);
+        assert!(longer.iter().eq(&[-1, -1, 2, 3, 4]));
+
+        let tracker = Rc::new(());
+        let mut shorter: LinkedList<i32> = (0..2).collect();
+        let other: LinkedList<_> = (0..5).map(|_| tracker.clone()).collect();
+        shorter.zip_with(other, |a, _| *a += 1);
+        assert!(shorter.iter().eq(&[1, 2]));
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}